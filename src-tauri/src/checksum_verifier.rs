@@ -0,0 +1,424 @@
+use crate::hash_generator::{digest_bytes, digest_file};
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// BSD-style tags (`SHA256 (file) = ...`) and the algorithm names they map to.
+const BSD_TAGS: [(&str, &str); 15] = [
+    ("MD2", "md2"),
+    ("MD4", "md4"),
+    ("MD5", "md5"),
+    ("SHA1", "sha1"),
+    ("SHA224", "sha224"),
+    ("SHA256", "sha256"),
+    ("SHA384", "sha384"),
+    ("SHA512", "sha512"),
+    ("SHA3-224", "sha3-224"),
+    ("SHA3-256", "sha3-256"),
+    ("SHA3-384", "sha3-384"),
+    ("SHA3-512", "sha3-512"),
+    ("BLAKE2b", "blake2b-512"),
+    ("BLAKE2s", "blake2s-256"),
+    ("BLAKE3", "blake3"),
+];
+
+/// Manifest name stems and extensions (`SHA256SUMS`, `file.iso.sha512`, ...) and their
+/// algorithm. A trailing `sum` or `sums` is accepted after each hint.
+const MANIFEST_NAME_HINTS: [(&str, &str); 7] = [
+    ("sha512", "sha512"),
+    ("sha384", "sha384"),
+    ("sha256", "sha256"),
+    ("sha224", "sha224"),
+    ("sha1", "sha1"),
+    ("md5", "md5"),
+    ("b2", "blake2b-512"),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum ChecksumStatus {
+    Ok,
+    Failed,
+    Missing,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChecksumEntry {
+    pub file_name: String,
+    pub algorithm: String,
+    pub expected: String,
+    pub actual: Option<String>,
+    pub status: ChecksumStatus,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChecksumReport {
+    pub entries: Vec<ChecksumEntry>,
+    pub ok_count: usize,
+    pub failed_count: usize,
+    pub missing_count: usize,
+    pub skipped_lines: Vec<usize>, // 1-based numbers of lines that are not checksum entries
+}
+
+/// One expected digest read from a manifest line.
+struct ManifestLine {
+    algorithm: String,
+    expected: String,
+    file_name: String,
+}
+
+fn is_hex_digest(value: &str) -> bool {
+    !value.is_empty()
+        && value.len().is_multiple_of(2)
+        && value.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+fn algorithm_from_bsd_tag(tag: &str) -> Option<&'static str> {
+    BSD_TAGS
+        .iter()
+        .find(|(bsd_tag, _)| bsd_tag.eq_ignore_ascii_case(tag))
+        .map(|(_, algorithm)| *algorithm)
+}
+
+fn bsd_tag_for(algorithm: &str) -> String {
+    BSD_TAGS
+        .iter()
+        .find(|(_, name)| *name == algorithm)
+        .map(|(tag, _)| tag.to_string())
+        .unwrap_or_else(|| algorithm.to_uppercase())
+}
+
+/// Looks only at the extension, then the stem, so names like
+/// `release-md5-notes.SHA256SUMS` are not taken for MD5.
+fn algorithm_from_manifest_name(manifest: &Path) -> Option<&'static str> {
+    let parts = [manifest.extension(), manifest.file_stem()];
+    parts.into_iter().flatten().find_map(|part| {
+        let part = part.to_string_lossy().to_lowercase();
+        let part = part.trim_end_matches("sums").trim_end_matches("sum");
+        MANIFEST_NAME_HINTS
+            .iter()
+            .find(|(hint, _)| *hint == part)
+            .map(|(_, algorithm)| *algorithm)
+    })
+}
+
+/// Whether `algorithm` produces hex digests `hex_len` characters long.
+fn has_digest_length(algorithm: &str, hex_len: usize) -> bool {
+    digest_bytes(&[], algorithm).is_ok_and(|digest| digest.len() * 2 == hex_len)
+}
+
+/// Guesses the algorithm of a bare hex digest from its length.
+fn algorithm_from_digest_length(hex_len: usize) -> Option<&'static str> {
+    match hex_len {
+        32 => Some("md5"),
+        40 => Some("sha1"),
+        56 => Some("sha224"),
+        64 => Some("sha256"),
+        96 => Some("sha384"),
+        128 => Some("sha512"),
+        _ => None,
+    }
+}
+
+/// Reverses the escaping GNU coreutils applies to names containing `\` or newlines.
+fn unescape_gnu_name(name: &str) -> String {
+    let mut result = String::with_capacity(name.len());
+    let mut chars = name.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next() {
+                Some('n') => result.push('\n'),
+                Some('r') => result.push('\r'),
+                Some(other) => result.push(other),
+                None => result.push('\\'),
+            }
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+fn escape_gnu_name(name: &str) -> (bool, String) {
+    if !name.contains(['\\', '\n', '\r']) {
+        return (false, name.to_string());
+    }
+    let escaped = name
+        .replace('\\', "\\\\")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r");
+    (true, escaped)
+}
+
+/// Parses `SHA256 (name) = digest`.
+fn parse_bsd_line(line: &str) -> Option<ManifestLine> {
+    let open = line.find(" (")?;
+    let close = line.rfind(") = ")?;
+    if close < open {
+        return None;
+    }
+    let algorithm = algorithm_from_bsd_tag(&line[..open])?;
+    let expected = line[close + 4..].trim();
+    if !is_hex_digest(expected) {
+        return None;
+    }
+    Some(ManifestLine {
+        algorithm: algorithm.to_string(),
+        expected: expected.to_lowercase(),
+        file_name: line[open + 2..close].to_string(),
+    })
+}
+
+/// Parses `digest  name`, `digest *name` or a lone digest (as in `file.iso.sha256`).
+fn parse_gnu_line(line: &str, default_name: &str) -> Option<(String, String)> {
+    let (escaped, line) = match line.strip_prefix('\\') {
+        Some(rest) => (true, rest),
+        None => (false, line),
+    };
+
+    let (digest, rest) = match line.find(char::is_whitespace) {
+        Some(split) => (&line[..split], &line[split..]),
+        None => (line, ""),
+    };
+    if !is_hex_digest(digest) {
+        return None;
+    }
+
+    // One separator space, then an optional ' ' (text) or '*' (binary) mode marker
+    let rest = rest.strip_prefix(' ').unwrap_or(rest);
+    let name = rest
+        .strip_prefix('*')
+        .or_else(|| rest.strip_prefix(' '))
+        .unwrap_or(rest);
+    let name = if name.is_empty() {
+        default_name.to_string()
+    } else if escaped {
+        unescape_gnu_name(name)
+    } else {
+        name.to_string()
+    };
+
+    Some((digest.to_lowercase(), name))
+}
+
+/// Reads every checksum entry from a manifest, returning entries and unparsable line numbers.
+fn parse_manifest(
+    content: &str,
+    manifest: &Path,
+    algorithm: Option<&str>,
+) -> (Vec<ManifestLine>, Vec<usize>) {
+    let name_hint = algorithm_from_manifest_name(manifest);
+    // `file.iso.sha256` may only contain the digest of `file.iso`
+    let default_name = manifest
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    let mut entries = Vec::new();
+    let mut skipped = Vec::new();
+    for (index, raw_line) in content.lines().enumerate() {
+        let line = raw_line.trim_end_matches('\r');
+        if line.trim().is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        if let Some(entry) = parse_bsd_line(line) {
+            entries.push(entry);
+            continue;
+        }
+
+        let parsed = parse_gnu_line(line, &default_name).and_then(|(expected, file_name)| {
+            // A name hint that disagrees with the digest length is ignored
+            let algorithm = algorithm
+                .or(name_hint.filter(|hint| has_digest_length(hint, expected.len())))
+                .or_else(|| algorithm_from_digest_length(expected.len()))?;
+            Some(ManifestLine {
+                algorithm: algorithm.to_string(),
+                expected,
+                file_name,
+            })
+        });
+        match parsed {
+            Some(entry) => entries.push(entry),
+            None => skipped.push(index + 1),
+        }
+    }
+    (entries, skipped)
+}
+
+fn check_entry(line: ManifestLine, base_dir: &Path) -> ChecksumEntry {
+    let path = base_dir.join(&line.file_name);
+    if !path.is_file() {
+        return ChecksumEntry {
+            file_name: line.file_name,
+            algorithm: line.algorithm,
+            expected: line.expected,
+            actual: None,
+            status: ChecksumStatus::Missing,
+            error: None,
+        };
+    }
+
+    let (actual, status, error) = match digest_file(&path, std::slice::from_ref(&line.algorithm)) {
+        Ok(digests) => {
+            let actual = digests.get(&line.algorithm).cloned().unwrap_or_default();
+            let status = if actual == line.expected {
+                ChecksumStatus::Ok
+            } else {
+                ChecksumStatus::Failed
+            };
+            (Some(actual), status, None)
+        }
+        Err(e) => (None, ChecksumStatus::Failed, Some(e)),
+    };
+
+    ChecksumEntry {
+        file_name: line.file_name,
+        algorithm: line.algorithm,
+        expected: line.expected,
+        actual,
+        status,
+        error,
+    }
+}
+
+fn verify_manifest(
+    manifest_path: &Path,
+    algorithm: Option<&str>,
+) -> Result<ChecksumReport, String> {
+    let content = fs::read_to_string(manifest_path)
+        .map_err(|e| format!("Failed to read checksum file: {}", e))?;
+    let base_dir = manifest_path.parent().unwrap_or_else(|| Path::new(""));
+
+    let (lines, skipped_lines) = parse_manifest(&content, manifest_path, algorithm);
+    if lines.is_empty() {
+        return Err("No checksum entries found in file".to_string());
+    }
+
+    let entries: Vec<ChecksumEntry> = lines
+        .into_par_iter()
+        .map(|line| check_entry(line, base_dir))
+        .collect();
+    let count = |status: ChecksumStatus| entries.iter().filter(|e| e.status == status).count();
+
+    Ok(ChecksumReport {
+        ok_count: count(ChecksumStatus::Ok),
+        failed_count: count(ChecksumStatus::Failed),
+        missing_count: count(ChecksumStatus::Missing),
+        entries,
+        skipped_lines,
+    })
+}
+
+/// Deepest directory containing all of `paths`.
+fn common_parent(paths: &[PathBuf]) -> PathBuf {
+    let mut parents = paths.iter().filter_map(|path| path.parent());
+    let first = match parents.next() {
+        Some(parent) => parent.to_path_buf(),
+        None => return PathBuf::new(),
+    };
+    parents.fold(first, |common, parent| {
+        common
+            .ancestors()
+            .find(|ancestor| parent.starts_with(ancestor))
+            .map(Path::to_path_buf)
+            .unwrap_or_default()
+    })
+}
+
+/// Renders `path` relative to `base_dir` with `/` separators, as manifests expect.
+fn manifest_name(path: &Path, base_dir: &Path) -> String {
+    path.strip_prefix(base_dir)
+        .unwrap_or(path)
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn build_manifest(
+    paths: &[PathBuf],
+    algorithm: &str,
+    format: &str,
+    base_dir: &Path,
+) -> Result<String, String> {
+    if format != "gnu" && format != "bsd" {
+        return Err(format!("Unsupported checksum file format: {}", format));
+    }
+
+    let algorithms = [algorithm.to_string()];
+    let digests: Vec<(String, String)> = paths
+        .par_iter()
+        .map(|path| {
+            let digests =
+                digest_file(path, &algorithms).map_err(|e| format!("{}: {}", path.display(), e))?;
+            let digest = digests.get(algorithm).cloned().unwrap_or_default();
+            Ok((manifest_name(path, base_dir), digest))
+        })
+        .collect::<Result<_, String>>()?;
+
+    let mut manifest = String::new();
+    for (name, digest) in digests {
+        if format == "bsd" {
+            manifest.push_str(&format!(
+                "{} ({}) = {}\n",
+                bsd_tag_for(algorithm),
+                name,
+                digest
+            ));
+        } else {
+            let (escaped, name) = escape_gnu_name(&name);
+            let prefix = if escaped { "\\" } else { "" };
+            manifest.push_str(&format!("{}{}  {}\n", prefix, digest, name));
+        }
+    }
+    Ok(manifest)
+}
+
+/// Verifies every file listed in a `sha256sum`/`SHA256SUMS`/BSD-style checksum file.
+///
+/// File names are resolved relative to the manifest's directory. `algorithm`
+/// applies to lines without a BSD tag; when omitted it is inferred from the
+/// manifest name (`SHA512SUMS`, `*.md5`) or the digest length.
+#[tauri::command]
+pub async fn verify_checksum_file(
+    manifest_path: String,
+    algorithm: Option<String>,
+) -> Result<ChecksumReport, String> {
+    let algorithm = algorithm.map(|name| name.trim().to_lowercase());
+    tauri::async_runtime::spawn_blocking(move || {
+        verify_manifest(Path::new(&manifest_path), algorithm.as_deref())
+    })
+    .await
+    .map_err(|e| format!("Checksum verification failed: {}", e))?
+}
+
+/// Builds a checksum file for `paths` in `gnu` (`sha256sum`) or `bsd` (`SHA256 (file) = ...`) format.
+///
+/// Names are written relative to `base_dir`, which defaults to the deepest
+/// directory containing every file.
+#[tauri::command]
+pub async fn generate_checksum_file(
+    paths: Vec<String>,
+    algorithm: String,
+    format: String,
+    base_dir: Option<String>,
+) -> Result<String, String> {
+    if paths.is_empty() {
+        return Err("No files selected".to_string());
+    }
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let paths: Vec<PathBuf> = paths.iter().map(PathBuf::from).collect();
+        let base_dir = base_dir
+            .map(PathBuf::from)
+            .unwrap_or_else(|| common_parent(&paths));
+        build_manifest(&paths, &algorithm.to_lowercase(), &format, &base_dir)
+    })
+    .await
+    .map_err(|e| format!("Checksum file generation failed: {}", e))?
+}
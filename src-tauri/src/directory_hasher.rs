@@ -0,0 +1,197 @@
+use crate::hash_generator::{digest_bytes, digest_file};
+use ignore::overrides::OverrideBuilder;
+use ignore::WalkBuilder;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DirectoryHashOptions {
+    pub algorithm: String,
+    #[serde(default)]
+    pub include: Vec<String>, // Globs a file must match, e.g. "*.rs", "src/**"
+    #[serde(default)]
+    pub exclude: Vec<String>, // Globs of files or directories to skip
+    #[serde(default)]
+    pub respect_gitignore: bool,
+    #[serde(default)]
+    pub include_hidden: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FileDigest {
+    pub path: String,
+    pub size: u64,
+    pub hash: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DirectoryHashResult {
+    pub algorithm: String,
+    pub root_hash: String,
+    pub file_count: usize,
+    pub total_bytes: u64,
+    pub files: Vec<FileDigest>,
+}
+
+/// A directory tree built from the hashed files, ordered by name.
+enum TreeNode {
+    File(Vec<u8>),
+    Directory(BTreeMap<String, TreeNode>),
+}
+
+impl TreeNode {
+    fn insert(&mut self, components: &[String], digest: Vec<u8>) {
+        let TreeNode::Directory(children) = self else {
+            return;
+        };
+        match components {
+            [] => {}
+            [name] => {
+                children.insert(name.clone(), TreeNode::File(digest));
+            }
+            [name, rest @ ..] => children
+                .entry(name.clone())
+                .or_insert_with(|| TreeNode::Directory(BTreeMap::new()))
+                .insert(rest, digest),
+        }
+    }
+
+    /// Digest of the node: files use their content digest, and a directory
+    /// hashes the concatenation of `"<kind> <name>\0<child digest>"` over its
+    /// children in byte order of their names, like a Git tree object.
+    fn digest(&self, algorithm: &str) -> Result<Vec<u8>, String> {
+        match self {
+            TreeNode::File(digest) => Ok(digest.clone()),
+            TreeNode::Directory(children) => {
+                let mut encoded = Vec::new();
+                for (name, child) in children {
+                    let kind = match child {
+                        TreeNode::File(_) => "file",
+                        TreeNode::Directory(_) => "dir",
+                    };
+                    encoded.extend_from_slice(kind.as_bytes());
+                    encoded.push(b' ');
+                    encoded.extend_from_slice(name.as_bytes());
+                    encoded.push(0);
+                    encoded.extend_from_slice(&child.digest(algorithm)?);
+                }
+                digest_bytes(&encoded, algorithm)
+            }
+        }
+    }
+}
+
+fn relative_components(path: &Path, root: &Path) -> Vec<String> {
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy().to_string())
+        .collect()
+}
+
+/// Lists the files under `root` that pass the glob filters and ignore rules.
+///
+/// Only `.gitignore` files inside `root` are honoured, never parent or global
+/// ones, so the same tree yields the same listing wherever it is checked out.
+fn collect_files(root: &Path, options: &DirectoryHashOptions) -> Result<Vec<PathBuf>, String> {
+    let mut overrides = OverrideBuilder::new(root);
+    for pattern in &options.include {
+        overrides
+            .add(pattern)
+            .map_err(|e| format!("Invalid include pattern '{}': {}", pattern, e))?;
+    }
+    for pattern in &options.exclude {
+        overrides
+            .add(&format!("!{}", pattern))
+            .map_err(|e| format!("Invalid exclude pattern '{}': {}", pattern, e))?;
+    }
+    let overrides = overrides
+        .build()
+        .map_err(|e| format!("Invalid file patterns: {}", e))?;
+
+    let walker = WalkBuilder::new(root)
+        .overrides(overrides)
+        .hidden(!options.include_hidden)
+        .parents(false)
+        .ignore(false)
+        .git_global(false)
+        .git_ignore(options.respect_gitignore)
+        .git_exclude(false)
+        .require_git(false)
+        .filter_entry(|entry| entry.file_name() != ".git")
+        .build();
+
+    let mut files = Vec::new();
+    for entry in walker {
+        let entry = entry.map_err(|e| format!("Failed to read directory: {}", e))?;
+        if entry
+            .file_type()
+            .is_some_and(|file_type| file_type.is_file())
+        {
+            files.push(entry.into_path());
+        }
+    }
+    Ok(files)
+}
+
+fn hash_tree(root: &Path, options: &DirectoryHashOptions) -> Result<DirectoryHashResult, String> {
+    if !root.is_dir() {
+        return Err(format!("Not a directory: {}", root.display()));
+    }
+    let algorithm = options.algorithm.trim().to_lowercase();
+    let algorithms = [algorithm.clone()];
+
+    let paths = collect_files(root, options)?;
+    let hashed: Vec<(Vec<String>, u64, Vec<u8>)> = paths
+        .par_iter()
+        .map(|path| {
+            let digests =
+                digest_file(path, &algorithms).map_err(|e| format!("{}: {}", path.display(), e))?;
+            let digest = hex::decode(&digests[&algorithm]).unwrap_or_default();
+            let size = path.metadata().map(|m| m.len()).unwrap_or(0);
+            Ok((relative_components(path, root), size, digest))
+        })
+        .collect::<Result<_, String>>()?;
+
+    let mut tree = TreeNode::Directory(BTreeMap::new());
+    let mut files = Vec::with_capacity(hashed.len());
+    let mut total_bytes = 0;
+    for (components, size, digest) in hashed {
+        files.push(FileDigest {
+            path: components.join("/"),
+            size,
+            hash: hex::encode(&digest),
+        });
+        total_bytes += size;
+        tree.insert(&components, digest);
+    }
+    files.sort_by(|a, b| a.path.cmp(&b.path));
+    let root_hash = hex::encode(tree.digest(&algorithm)?);
+
+    Ok(DirectoryHashResult {
+        algorithm,
+        root_hash,
+        file_count: files.len(),
+        total_bytes,
+        files,
+    })
+}
+
+/// Hashes every file under `path` in parallel and folds them into one root hash.
+///
+/// Two directories with the same relative file names and contents produce the
+/// same root hash; empty directories do not contribute to it.
+#[tauri::command]
+pub async fn hash_directory(
+    path: String,
+    options: DirectoryHashOptions,
+) -> Result<DirectoryHashResult, String> {
+    tauri::async_runtime::spawn_blocking(move || hash_tree(Path::new(&path), &options))
+        .await
+        .map_err(|e| format!("Directory hashing failed: {}", e))?
+}
@@ -0,0 +1,155 @@
+use crate::hash_generator::{algorithms_with_digest_len, digest_file, is_supported_algorithm};
+use base64::{
+    engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD},
+    Engine as _,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Algorithm used to compare two files when none is given.
+const DEFAULT_COMPARE_ALGORITHM: &str = "sha256";
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HashComparison {
+    pub is_match: bool,
+    pub algorithm: Option<String>, // Algorithm that matched, or the one used for two files
+    pub candidates: Vec<String>,   // Algorithms that were tried
+    pub expected: String,          // Expected digest, or the second file's digest, as lowercase hex
+    pub actual: HashMap<String, String>, // Digest of the first file per candidate
+}
+
+/// A pasted digest reduced to raw bytes, plus the algorithm named by its prefix.
+struct ExpectedDigest {
+    bytes: Vec<u8>,
+    algorithm: Option<String>,
+}
+
+/// Splits `sha256:<hex>` (OCI), `sha256=<hex>` (GitHub) and `sha256-<base64>` (SRI) prefixes.
+fn split_algorithm_prefix(token: &str) -> (Option<String>, &str) {
+    for separator in [':', '=', '-'] {
+        // Algorithm names contain '-' themselves, so split at the last one for SRI
+        let split = if separator == '-' {
+            token.rsplit_once(separator)
+        } else {
+            token.split_once(separator)
+        };
+        if let Some((prefix, value)) = split {
+            let name = prefix.to_lowercase();
+            if is_supported_algorithm(&name) {
+                return (Some(name), value);
+            }
+        }
+    }
+    (None, token)
+}
+
+fn parse_expected(input: &str) -> Result<ExpectedDigest, String> {
+    // A pasted `sha256sum` line carries the file name after the digest
+    let token = input
+        .split_whitespace()
+        .next()
+        .ok_or("Expected hash is empty")?;
+    let (algorithm, value) = split_algorithm_prefix(token);
+
+    let is_hex = value.len().is_multiple_of(2) && value.chars().all(|c| c.is_ascii_hexdigit());
+    let bytes = if is_hex {
+        hex::decode(value).map_err(|e| format!("Invalid hex digest: {}", e))?
+    } else {
+        STANDARD
+            .decode(value)
+            .or_else(|_| URL_SAFE_NO_PAD.decode(value.trim_end_matches('=')))
+            .map_err(|_| "Expected hash is neither hex nor base64".to_string())?
+    };
+
+    Ok(ExpectedDigest { bytes, algorithm })
+}
+
+fn compare_files(
+    path: &Path,
+    other_path: &Path,
+    algorithm: &str,
+) -> Result<HashComparison, String> {
+    let algorithms = [algorithm.to_string()];
+    let (first, second) = rayon::join(
+        || digest_file(path, &algorithms),
+        || digest_file(other_path, &algorithms),
+    );
+    let first = first.map_err(|e| format!("{}: {}", path.display(), e))?;
+    let second = second.map_err(|e| format!("{}: {}", other_path.display(), e))?;
+
+    let expected = second.get(algorithm).cloned().unwrap_or_default();
+    Ok(HashComparison {
+        is_match: first.get(algorithm) == Some(&expected),
+        algorithm: Some(algorithm.to_string()),
+        candidates: algorithms.to_vec(),
+        expected,
+        actual: first,
+    })
+}
+
+fn compare_with_expected(
+    path: &Path,
+    expected: &str,
+    algorithm: Option<String>,
+) -> Result<HashComparison, String> {
+    let expected = parse_expected(expected)?;
+    let candidates: Vec<String> = match algorithm.or(expected.algorithm) {
+        Some(name) => vec![name],
+        None => algorithms_with_digest_len(expected.bytes.len())
+            .into_iter()
+            .map(String::from)
+            .collect(),
+    };
+    if candidates.is_empty() {
+        return Err(format!(
+            "No supported algorithm produces a {}-byte digest",
+            expected.bytes.len()
+        ));
+    }
+
+    let expected_hex = hex::encode(&expected.bytes);
+    let actual = digest_file(path, &candidates)?;
+    let matched = candidates
+        .iter()
+        .find(|name| actual.get(name.as_str()) == Some(&expected_hex))
+        .cloned();
+
+    Ok(HashComparison {
+        is_match: matched.is_some(),
+        algorithm: matched.or_else(|| (candidates.len() == 1).then(|| candidates[0].clone())),
+        candidates,
+        expected: expected_hex,
+        actual,
+    })
+}
+
+/// Compares a file against a second file or against an expected digest.
+///
+/// With an `expected` digest and no `algorithm`, every algorithm whose output
+/// has the digest's length is tried (hex, base64, `sha256:`, `sha256=` and SRI
+/// `sha256-` forms are accepted) and the matching one is reported.
+#[tauri::command]
+pub async fn compare_hashes(
+    path: String,
+    other_path: Option<String>,
+    expected: Option<String>,
+    algorithm: Option<String>,
+) -> Result<HashComparison, String> {
+    let algorithm = algorithm.map(|name| name.trim().to_lowercase());
+    tauri::async_runtime::spawn_blocking(move || match (other_path, expected) {
+        (Some(_), Some(_)) => {
+            Err("Provide either a second file or an expected hash, not both".to_string())
+        }
+        (Some(other_path), None) => compare_files(
+            Path::new(&path),
+            Path::new(&other_path),
+            algorithm.as_deref().unwrap_or(DEFAULT_COMPARE_ALGORITHM),
+        ),
+        (None, Some(expected)) => compare_with_expected(Path::new(&path), &expected, algorithm),
+        (None, None) => Err("Provide a second file or an expected hash".to_string()),
+    })
+    .await
+    .map_err(|e| format!("Hash comparison failed: {}", e))?
+}
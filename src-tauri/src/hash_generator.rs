@@ -0,0 +1,787 @@
+use rayon::prelude::*;
+use serde::Serialize;
+use sha1::Sha1;
+use base64::{
+    engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD},
+    Engine as _,
+};
+use blake2::{Blake2b512, Blake2s256};
+use crc::{Crc, CRC_16_ARC, CRC_16_DNP, CRC_16_GENIBUS, CRC_16_IBM_3740, CRC_16_IBM_SDLC,
+    CRC_16_KERMIT, CRC_16_MAXIM_DOW, CRC_16_MODBUS, CRC_16_T10_DIF, CRC_16_USB, CRC_16_XMODEM,
+    CRC_32_AUTOSAR, CRC_32_BZIP2, CRC_32_CKSUM, CRC_32_ISCSI, CRC_32_ISO_HDLC, CRC_32_JAMCRC,
+    CRC_32_MPEG_2, CRC_32_AIXM, CRC_64_ECMA_182, CRC_64_GO_ISO, CRC_64_NVME, CRC_64_REDIS, CRC_64_XZ};
+use data_encoding::BASE32;
+use md2::Md2;
+use md4::Md4;
+use md5::Md5;
+use sha2::{Digest, Sha224, Sha256, Sha384, Sha512};
+use sha3::digest::{ExtendableOutput, Update, XofReader};
+use sha3::{Keccak256, Sha3_224, Sha3_256, Sha3_384, Sha3_512, Shake128, Shake256};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, Read};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, State};
+use xxhash_rust::xxh3::Xxh3Default;
+use xxhash_rust::xxh32::Xxh32;
+use xxhash_rust::xxh64::Xxh64;
+
+/// Size of each read when streaming a file through the hashers.
+const CHUNK_SIZE: usize = 1024 * 1024;
+
+/// Event emitted while `hash_file` is running.
+const PROGRESS_EVENT: &str = "hash-progress";
+
+/// Minimum time between two progress events for the same job.
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Alphabet of Nix's base32, which omits e, o, t and u.
+const NIX_BASE32_ALPHABET: &[u8; 32] = b"0123456789abcdfghijklmnpqrsvwxyz";
+
+/// Algorithms allowed in Subresource Integrity metadata.
+const SRI_ALGORITHMS: [&str; 3] = ["sha256", "sha384", "sha512"];
+
+/// Algorithms registered for OCI content digests.
+const OCI_ALGORITHMS: [&str; 2] = ["sha256", "sha512"];
+
+/// Algorithms computed when the caller does not select any.
+const DEFAULT_ALGORITHMS: [&str; 7] =
+    ["md5", "sha1", "sha224", "sha256", "sha384", "sha512", "keccak256"];
+
+/// Every named algorithm besides the CRC variants; those outside
+/// `DEFAULT_ALGORITHMS` are only computed when selected explicitly.
+///
+/// `keccak256` is the original Keccak submission used by Ethereum; it produces
+/// different digests than the FIPS 202 `sha3-256` that replaced it.
+const ALGORITHMS: [&str; 22] = [
+    "md2", "md4", "md5", "sha1", "sha224", "sha256", "sha384", "sha512", "sha3-224", "sha3-256",
+    "sha3-384", "sha3-512", "shake128", "shake256", "keccak256", "blake2b-512", "blake2s-256",
+    "blake3", "xxh32", "xxh64", "xxh3-64", "xxh3-128",
+];
+
+/// Default extendable-output sizes, matching twice the security strength of each variant.
+const SHAKE128_DEFAULT_BITS: usize = 256;
+const SHAKE256_DEFAULT_BITS: usize = 512;
+const BLAKE3_DEFAULT_BITS: usize = 256;
+
+/// CRC parameter sets selectable by name, taken from the RevEng CRC catalogue.
+static CRC16_CATALOG: [(&str, Crc<u16>); 11] = [
+    ("crc16-arc", Crc::<u16>::new(&CRC_16_ARC)),
+    ("crc16-ccitt-false", Crc::<u16>::new(&CRC_16_IBM_3740)),
+    ("crc16-xmodem", Crc::<u16>::new(&CRC_16_XMODEM)),
+    ("crc16-kermit", Crc::<u16>::new(&CRC_16_KERMIT)),
+    ("crc16-modbus", Crc::<u16>::new(&CRC_16_MODBUS)),
+    ("crc16-usb", Crc::<u16>::new(&CRC_16_USB)),
+    ("crc16-x25", Crc::<u16>::new(&CRC_16_IBM_SDLC)),
+    ("crc16-maxim", Crc::<u16>::new(&CRC_16_MAXIM_DOW)),
+    ("crc16-dnp", Crc::<u16>::new(&CRC_16_DNP)),
+    ("crc16-genibus", Crc::<u16>::new(&CRC_16_GENIBUS)),
+    ("crc16-t10-dif", Crc::<u16>::new(&CRC_16_T10_DIF)),
+];
+
+static CRC32_CATALOG: [(&str, Crc<u32>); 8] = [
+    ("crc32", Crc::<u32>::new(&CRC_32_ISO_HDLC)),
+    ("crc32c", Crc::<u32>::new(&CRC_32_ISCSI)),
+    ("crc32-bzip2", Crc::<u32>::new(&CRC_32_BZIP2)),
+    ("crc32-mpeg2", Crc::<u32>::new(&CRC_32_MPEG_2)),
+    ("crc32-jamcrc", Crc::<u32>::new(&CRC_32_JAMCRC)),
+    ("crc32-posix", Crc::<u32>::new(&CRC_32_CKSUM)),
+    ("crc32-autosar", Crc::<u32>::new(&CRC_32_AUTOSAR)),
+    ("crc32-aixm", Crc::<u32>::new(&CRC_32_AIXM)),
+];
+
+static CRC64_CATALOG: [(&str, Crc<u64>); 5] = [
+    ("crc64-ecma", Crc::<u64>::new(&CRC_64_ECMA_182)),
+    ("crc64-xz", Crc::<u64>::new(&CRC_64_XZ)),
+    ("crc64-go-iso", Crc::<u64>::new(&CRC_64_GO_ISO)),
+    ("crc64-nvme", Crc::<u64>::new(&CRC_64_NVME)),
+    ("crc64-redis", Crc::<u64>::new(&CRC_64_REDIS)),
+];
+
+/// Parameters of a selectable CRC variant, as listed by `list_crc_variants`.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CrcVariant {
+    pub name: String,
+    pub width: u8,
+    pub poly: String,
+    pub init: String,
+    pub reflect_in: bool,
+    pub reflect_out: bool,
+    pub xorout: String,
+    pub check: String,
+}
+
+/// Cancellation flags of the running `hash_file` jobs, keyed by job id.
+#[derive(Default)]
+pub struct HashJobs(Mutex<HashMap<String, Arc<AtomicBool>>>);
+
+impl HashJobs {
+    /// Job ids must be unique while running, so `finish` never drops another job's flag.
+    fn register(&self, job_id: &str) -> Result<Arc<AtomicBool>, String> {
+        let mut jobs = self.0.lock().unwrap();
+        if jobs.contains_key(job_id) {
+            return Err(format!("Hash job {} is already running", job_id));
+        }
+        let flag = Arc::new(AtomicBool::new(false));
+        jobs.insert(job_id.to_string(), flag.clone());
+        Ok(flag)
+    }
+
+    fn finish(&self, job_id: &str) {
+        self.0.lock().unwrap().remove(job_id);
+    }
+
+    fn cancel(&self, job_id: &str) -> bool {
+        match self.0.lock().unwrap().get(job_id) {
+            Some(flag) => {
+                flag.store(true, Ordering::Relaxed);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HashProgress {
+    pub job_id: Option<String>,
+    pub bytes_processed: u64,
+    pub total_bytes: u64,
+    pub bytes_per_second: f64,
+    pub eta_seconds: Option<f64>,
+}
+
+/// Incremental state for a single hash algorithm, fed chunk by chunk.
+enum Hasher {
+    Md2(Md2),
+    Md4(Md4),
+    Md5(Md5),
+    Sha1(Sha1),
+    Sha224(Sha224),
+    Sha256(Sha256),
+    Sha384(Sha384),
+    Sha512(Sha512),
+    Sha3_224(Sha3_224),
+    Sha3_256(Sha3_256),
+    Sha3_384(Sha3_384),
+    Sha3_512(Sha3_512),
+    /// SHAKE state together with the requested output length in bytes.
+    Shake128(Shake128, usize),
+    Shake256(Shake256, usize),
+    Keccak256(Keccak256),
+    Blake2b512(Blake2b512),
+    Blake2s256(Blake2s256),
+    /// BLAKE3 state together with the requested output length in bytes.
+    Blake3(Box<blake3::Hasher>, usize),
+    Xxh32(Xxh32),
+    Xxh64(Xxh64),
+    Xxh3_64(Box<Xxh3Default>),
+    Xxh3_128(Box<Xxh3Default>),
+    Crc16(crc::Digest<'static, u16>),
+    Crc32(crc::Digest<'static, u32>),
+    Crc64(crc::Digest<'static, u64>),
+}
+
+impl Hasher {
+    /// Creates the hasher for `algorithm`; `xof_bits` sets the SHAKE and BLAKE3 output length.
+    fn new(algorithm: &str, xof_bits: Option<usize>) -> Result<Self, String> {
+        let xof_bytes = |default_bits: usize| -> Result<usize, String> {
+            let bits = xof_bits.unwrap_or(default_bits);
+            if bits == 0 || !bits.is_multiple_of(8) {
+                return Err(format!(
+                    "Output length must be a positive multiple of 8 bits, got {}",
+                    bits
+                ));
+            }
+            Ok(bits / 8)
+        };
+
+        if let Some((_, crc)) = CRC16_CATALOG.iter().find(|(name, _)| *name == algorithm) {
+            return Ok(Hasher::Crc16(crc.digest()));
+        }
+        if let Some((_, crc)) = CRC32_CATALOG.iter().find(|(name, _)| *name == algorithm) {
+            return Ok(Hasher::Crc32(crc.digest()));
+        }
+        if let Some((_, crc)) = CRC64_CATALOG.iter().find(|(name, _)| *name == algorithm) {
+            return Ok(Hasher::Crc64(crc.digest()));
+        }
+
+        let hasher = match algorithm {
+            "md2" => Hasher::Md2(Md2::new()),
+            "md4" => Hasher::Md4(Md4::new()),
+            "md5" => Hasher::Md5(Md5::new()),
+            "sha1" => Hasher::Sha1(Sha1::new()),
+            "sha224" => Hasher::Sha224(Sha224::new()),
+            "sha256" => Hasher::Sha256(Sha256::new()),
+            "sha384" => Hasher::Sha384(Sha384::new()),
+            "sha512" => Hasher::Sha512(Sha512::new()),
+            "sha3-224" => Hasher::Sha3_224(Sha3_224::new()),
+            "sha3-256" => Hasher::Sha3_256(Sha3_256::new()),
+            "sha3-384" => Hasher::Sha3_384(Sha3_384::new()),
+            "sha3-512" => Hasher::Sha3_512(Sha3_512::new()),
+            "shake128" => Hasher::Shake128(Shake128::default(), xof_bytes(SHAKE128_DEFAULT_BITS)?),
+            "shake256" => Hasher::Shake256(Shake256::default(), xof_bytes(SHAKE256_DEFAULT_BITS)?),
+            "keccak256" => Hasher::Keccak256(Keccak256::new()),
+            "blake2b-512" => Hasher::Blake2b512(Blake2b512::new()),
+            "blake2s-256" => Hasher::Blake2s256(Blake2s256::new()),
+            "blake3" => Hasher::Blake3(Box::default(), xof_bytes(BLAKE3_DEFAULT_BITS)?),
+            "xxh32" => Hasher::Xxh32(Xxh32::new(0)),
+            "xxh64" => Hasher::Xxh64(Xxh64::new(0)),
+            "xxh3-64" => Hasher::Xxh3_64(Box::default()),
+            "xxh3-128" => Hasher::Xxh3_128(Box::default()),
+            _ => return Err(format!("Unsupported hash algorithm: {}", algorithm)),
+        };
+        Ok(hasher)
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        match self {
+            Hasher::Md2(h) => Digest::update(h, data),
+            Hasher::Md4(h) => Digest::update(h, data),
+            Hasher::Md5(h) => Digest::update(h, data),
+            Hasher::Sha1(h) => Digest::update(h, data),
+            Hasher::Sha224(h) => Digest::update(h, data),
+            Hasher::Sha256(h) => Digest::update(h, data),
+            Hasher::Sha384(h) => Digest::update(h, data),
+            Hasher::Sha512(h) => Digest::update(h, data),
+            Hasher::Sha3_224(h) => Digest::update(h, data),
+            Hasher::Sha3_256(h) => Digest::update(h, data),
+            Hasher::Sha3_384(h) => Digest::update(h, data),
+            Hasher::Sha3_512(h) => Digest::update(h, data),
+            Hasher::Shake128(h, _) => Update::update(h, data),
+            Hasher::Shake256(h, _) => Update::update(h, data),
+            Hasher::Keccak256(h) => Digest::update(h, data),
+            Hasher::Blake2b512(h) => Digest::update(h, data),
+            Hasher::Blake2s256(h) => Digest::update(h, data),
+            Hasher::Blake3(h, _) => {
+                h.update(data);
+            }
+            Hasher::Xxh32(h) => h.update(data),
+            Hasher::Xxh64(h) => h.update(data),
+            Hasher::Xxh3_64(h) => h.update(data),
+            Hasher::Xxh3_128(h) => h.update(data),
+            Hasher::Crc16(digest) => digest.update(data),
+            Hasher::Crc32(digest) => digest.update(data),
+            Hasher::Crc64(digest) => digest.update(data),
+        }
+    }
+
+    fn finalize(self) -> Vec<u8> {
+        match self {
+            Hasher::Md2(h) => h.finalize().to_vec(),
+            Hasher::Md4(h) => h.finalize().to_vec(),
+            Hasher::Md5(h) => h.finalize().to_vec(),
+            Hasher::Sha1(h) => h.finalize().to_vec(),
+            Hasher::Sha224(h) => h.finalize().to_vec(),
+            Hasher::Sha256(h) => h.finalize().to_vec(),
+            Hasher::Sha384(h) => h.finalize().to_vec(),
+            Hasher::Sha512(h) => h.finalize().to_vec(),
+            Hasher::Sha3_224(h) => h.finalize().to_vec(),
+            Hasher::Sha3_256(h) => h.finalize().to_vec(),
+            Hasher::Sha3_384(h) => h.finalize().to_vec(),
+            Hasher::Sha3_512(h) => h.finalize().to_vec(),
+            Hasher::Shake128(h, len) => read_xof(h.finalize_xof(), len),
+            Hasher::Shake256(h, len) => read_xof(h.finalize_xof(), len),
+            Hasher::Keccak256(h) => h.finalize().to_vec(),
+            Hasher::Blake2b512(h) => h.finalize().to_vec(),
+            Hasher::Blake2s256(h) => h.finalize().to_vec(),
+            Hasher::Blake3(h, len) => {
+                let mut output = vec![0u8; len];
+                h.finalize_xof().fill(&mut output);
+                output
+            }
+            // xxHash and CRC values are shown big-endian, as xxhsum and crc tools print them
+            Hasher::Xxh32(h) => h.digest().to_be_bytes().to_vec(),
+            Hasher::Xxh64(h) => h.digest().to_be_bytes().to_vec(),
+            Hasher::Xxh3_64(h) => h.digest().to_be_bytes().to_vec(),
+            Hasher::Xxh3_128(h) => h.digest128().to_be_bytes().to_vec(),
+            Hasher::Crc16(digest) => digest.finalize().to_be_bytes().to_vec(),
+            Hasher::Crc32(digest) => digest.finalize().to_be_bytes().to_vec(),
+            Hasher::Crc64(digest) => digest.finalize().to_be_bytes().to_vec(),
+        }
+    }
+}
+
+/// Describes a CRC parameter set with values rendered at the variant's width.
+fn describe_crc<W: crc::Width + Copy + Into<u64>>(name: &str, algorithm: &crc::Algorithm<W>) -> CrcVariant {
+    let digits = (algorithm.width as usize).div_ceil(4);
+    let format = |value: W| format!("0x{:0digits$X}", value.into(), digits = digits);
+    CrcVariant {
+        name: name.to_string(),
+        width: algorithm.width,
+        poly: format(algorithm.poly),
+        init: format(algorithm.init),
+        reflect_in: algorithm.refin,
+        reflect_out: algorithm.refout,
+        xorout: format(algorithm.xorout),
+        check: format(algorithm.check),
+    }
+}
+
+fn read_xof<R: XofReader>(mut reader: R, len: usize) -> Vec<u8> {
+    let mut output = vec![0u8; len];
+    reader.read(&mut output);
+    output
+}
+
+fn format_hex(data: &[u8], lowercase: bool) -> String {
+    if lowercase {
+        hex::encode(data)
+    } else {
+        hex::encode_upper(data)
+    }
+}
+
+/// Nix's base32: 5-bit groups are taken from the end of the digest first.
+fn format_nix_base32(data: &[u8]) -> String {
+    if data.is_empty() {
+        return String::new();
+    }
+    let len = (data.len() * 8 - 1) / 5 + 1;
+    (0..len)
+        .rev()
+        .map(|n| {
+            let bit = n * 5;
+            let (i, shift) = (bit / 8, bit % 8);
+            let low = u16::from(data[i]) >> shift;
+            let high = data.get(i + 1).map_or(0, |&b| u16::from(b) << (8 - shift));
+            NIX_BASE32_ALPHABET[usize::from((low | high) & 0x1f)] as char
+        })
+        .collect()
+}
+
+/// How digests are rendered in the command results.
+#[derive(Clone, Copy)]
+enum OutputEncoding {
+    Hex { lowercase: bool },
+    Base64,
+    Base64Url,
+    Base32,
+    NixBase32,
+    Sri,
+    Oci,
+}
+
+impl OutputEncoding {
+    /// `lowercase` only applies to hex, the default when no encoding is given.
+    fn parse(encoding: Option<&str>, lowercase: bool) -> Result<Self, String> {
+        let encoding = encoding.map(|e| e.trim().to_lowercase());
+        match encoding.as_deref() {
+            None | Some("") | Some("hex") => Ok(OutputEncoding::Hex { lowercase }),
+            Some("base64") => Ok(OutputEncoding::Base64),
+            Some("base64url") => Ok(OutputEncoding::Base64Url),
+            Some("base32") => Ok(OutputEncoding::Base32),
+            Some("nix32") | Some("nix-base32") => Ok(OutputEncoding::NixBase32),
+            Some("sri") => Ok(OutputEncoding::Sri),
+            Some("oci") => Ok(OutputEncoding::Oci),
+            Some(other) => Err(format!("Unsupported output encoding: {}", other)),
+        }
+    }
+
+    /// Returns `None` when the format defines no prefix for `algorithm`, as
+    /// SRI and OCI digests only exist for a few SHA-2 variants.
+    fn encode(self, algorithm: &str, digest: &[u8]) -> Option<String> {
+        let encoded = match self {
+            OutputEncoding::Hex { lowercase } => format_hex(digest, lowercase),
+            OutputEncoding::Base64 => STANDARD.encode(digest),
+            OutputEncoding::Base64Url => URL_SAFE_NO_PAD.encode(digest),
+            OutputEncoding::Base32 => BASE32.encode(digest),
+            OutputEncoding::NixBase32 => format_nix_base32(digest),
+            // Subresource Integrity, e.g. `sha256-<base64>`
+            OutputEncoding::Sri if SRI_ALGORITHMS.contains(&algorithm) => {
+                format!("{}-{}", algorithm, STANDARD.encode(digest))
+            }
+            // OCI content descriptors, e.g. `sha256:<hex>`
+            OutputEncoding::Oci if OCI_ALGORITHMS.contains(&algorithm) => {
+                format!("{}:{}", algorithm, hex::encode(digest))
+            }
+            OutputEncoding::Sri | OutputEncoding::Oci => return None,
+        };
+        Some(encoded)
+    }
+}
+
+/// Builds one hasher per requested algorithm, or for the default set when no
+/// selection is given. Names are case-insensitive and duplicates are ignored.
+fn new_hashers(
+    algorithms: Option<&[String]>,
+    xof_bits: Option<usize>,
+) -> Result<Vec<(String, Hasher)>, String> {
+    let names: Vec<String> = match algorithms {
+        Some(selected) => selected.iter().map(|name| name.trim().to_lowercase()).collect(),
+        None => DEFAULT_ALGORITHMS.iter().map(|name| name.to_string()).collect(),
+    };
+    if names.is_empty() {
+        return Err("No hash algorithm selected".to_string());
+    }
+
+    let mut hashers: Vec<(String, Hasher)> = Vec::with_capacity(names.len());
+    for name in names {
+        if hashers.iter().any(|(existing, _)| *existing == name) {
+            continue;
+        }
+        let hasher = Hasher::new(&name, xof_bits)?;
+        hashers.push((name, hasher));
+    }
+    Ok(hashers)
+}
+
+fn finalize_hashers(
+    hashers: Vec<(String, Hasher)>,
+    encoding: OutputEncoding,
+) -> HashMap<String, String> {
+    hashers
+        .into_par_iter()
+        .filter_map(|(name, hasher)| {
+            let digest = encoding.encode(&name, &hasher.finalize())?;
+            Some((name, digest))
+        })
+        .collect()
+}
+
+/// Fills `buf` from `reader`, returning fewer bytes than `buf.len()` only at EOF.
+fn read_chunk<R: Read>(reader: &mut R, buf: &mut [u8]) -> io::Result<usize> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        match reader.read(&mut buf[filled..]) {
+            Ok(0) => break,
+            Ok(n) => filled += n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(filled)
+}
+
+/// Streams `reader` through every hasher in fixed-size chunks and returns the byte count.
+///
+/// Two buffers are alternated so the next chunk is read while the current one is
+/// fed to all hashers in parallel, keeping memory bounded to `2 * CHUNK_SIZE`.
+/// `on_chunk` receives the running byte count after each chunk and can abort the
+/// stream by returning an error.
+fn stream_into_hashers<R, F>(
+    reader: &mut R,
+    hashers: &mut [(String, Hasher)],
+    mut on_chunk: F,
+) -> Result<u64, String>
+where
+    R: Read + Send,
+    F: FnMut(u64) -> Result<(), String>,
+{
+    let read_error = |e: io::Error| format!("Failed to read file: {}", e);
+    let mut current = vec![0u8; CHUNK_SIZE];
+    let mut next = vec![0u8; CHUNK_SIZE];
+    let mut total = 0u64;
+
+    let mut filled = read_chunk(reader, &mut current).map_err(read_error)?;
+    while filled > 0 {
+        let data = &current[..filled];
+        let (read_result, ()) = rayon::join(
+            || read_chunk(reader, &mut next),
+            || hashers.par_iter_mut().for_each(|(_, hasher)| hasher.update(data)),
+        );
+        total += filled as u64;
+        on_chunk(total)?;
+        filled = read_result.map_err(read_error)?;
+        std::mem::swap(&mut current, &mut next);
+    }
+
+    Ok(total)
+}
+
+/// Hashes the file at `path` with `algorithms`, returning lowercase hex digests.
+///
+/// This is the plain streaming path used by the checksum tools, without
+/// progress events or cancellation.
+pub(crate) fn digest_file(
+    path: &Path,
+    algorithms: &[String],
+) -> Result<HashMap<String, String>, String> {
+    let mut hashers = new_hashers(Some(algorithms), None)?;
+    let mut file = File::open(path).map_err(|e| format!("Failed to open file: {}", e))?;
+    stream_into_hashers(&mut file, &mut hashers, |_| Ok(()))?;
+    Ok(finalize_hashers(
+        hashers,
+        OutputEncoding::Hex { lowercase: true },
+    ))
+}
+
+/// Hashes an in-memory buffer with a single algorithm, returning the raw digest.
+pub(crate) fn digest_bytes(data: &[u8], algorithm: &str) -> Result<Vec<u8>, String> {
+    let mut hasher = Hasher::new(algorithm, None)?;
+    hasher.update(data);
+    Ok(hasher.finalize())
+}
+
+/// Every algorithm name accepted by the hash commands.
+fn supported_algorithms() -> Vec<&'static str> {
+    let crc_names = CRC16_CATALOG
+        .iter()
+        .map(|(name, _)| *name)
+        .chain(CRC32_CATALOG.iter().map(|(name, _)| *name))
+        .chain(CRC64_CATALOG.iter().map(|(name, _)| *name));
+    ALGORITHMS.iter().copied().chain(crc_names).collect()
+}
+
+pub(crate) fn is_supported_algorithm(name: &str) -> bool {
+    supported_algorithms().contains(&name)
+}
+
+/// Algorithms whose default output is `len` bytes long, used to guess the
+/// algorithm behind a pasted digest.
+pub(crate) fn algorithms_with_digest_len(len: usize) -> Vec<&'static str> {
+    supported_algorithms()
+        .into_iter()
+        .filter(|name| digest_bytes(&[], name).is_ok_and(|digest| digest.len() == len))
+        .collect()
+}
+
+fn hash_file_with_progress(
+    app: &AppHandle,
+    path: &str,
+    mut hashers: Vec<(String, Hasher)>,
+    encoding: OutputEncoding,
+    job_id: Option<String>,
+    cancelled: &AtomicBool,
+) -> Result<HashMap<String, String>, String> {
+    let start_time = Instant::now();
+
+    let mut file = File::open(path).map_err(|e| format!("Failed to open file: {}", e))?;
+    let total_bytes = file.metadata().map(|m| m.len()).unwrap_or(0);
+
+    let emit_progress = |bytes_processed: u64| {
+        let elapsed = start_time.elapsed().as_secs_f64();
+        let bytes_per_second = if elapsed > 0.0 { bytes_processed as f64 / elapsed } else { 0.0 };
+        let eta_seconds = if bytes_per_second > 0.0 && total_bytes >= bytes_processed {
+            Some((total_bytes - bytes_processed) as f64 / bytes_per_second)
+        } else {
+            None
+        };
+        let _ = app.emit(
+            PROGRESS_EVENT,
+            HashProgress {
+                job_id: job_id.clone(),
+                bytes_processed,
+                total_bytes,
+                bytes_per_second,
+                eta_seconds,
+            },
+        );
+    };
+
+    let mut last_emit = Instant::now();
+    let processed = stream_into_hashers(&mut file, &mut hashers, |bytes_processed| {
+        if cancelled.load(Ordering::Relaxed) {
+            return Err("Hash job cancelled".to_string());
+        }
+        if last_emit.elapsed() >= PROGRESS_INTERVAL {
+            emit_progress(bytes_processed);
+            last_emit = Instant::now();
+        }
+        Ok(())
+    })?;
+    emit_progress(processed);
+    let results = finalize_hashers(hashers, encoding);
+
+    let duration = start_time.elapsed();
+    println!("Hash file computation took: {:.2?} for {} bytes", duration, processed);
+
+    Ok(results)
+}
+
+/// Hashes a string with the requested `algorithms`, or the default set when omitted.
+///
+/// `xof_bits` overrides the output length of SHAKE128, SHAKE256 and BLAKE3.
+/// `encoding` is one of `hex` (default), `base64`, `base64url`, `base32`,
+/// `nix32`, `sri` or `oci`; `lowercase` only affects hex output. SRI results
+/// only include sha256/384/512 and OCI results sha256/512, the algorithms
+/// those formats define.
+#[tauri::command]
+pub fn hash_string(
+    input: &str,
+    lowercase: bool,
+    algorithms: Option<Vec<String>>,
+    xof_bits: Option<usize>,
+    encoding: Option<String>,
+) -> Result<HashMap<String, String>, String> {
+    let start_time = Instant::now();
+    let bytes = input.as_bytes();
+    let encoding = OutputEncoding::parse(encoding.as_deref(), lowercase)?;
+
+    let mut hashers = new_hashers(algorithms.as_deref(), xof_bits)?;
+    hashers.par_iter_mut().for_each(|(_, hasher)| hasher.update(bytes));
+    let results = finalize_hashers(hashers, encoding);
+
+    let duration = start_time.elapsed();
+    println!("Hash string computation took: {:.2?} for {} bytes", duration, bytes.len());
+
+    Ok(results)
+}
+
+/// Hashes a file on a worker thread, emitting `hash-progress` events as it goes.
+///
+/// Only the requested `algorithms` are computed; the default set when omitted.
+/// When a `job_id` is given the job can be aborted with `cancel_hash_job`.
+/// Digests are rendered as in `hash_string`.
+#[tauri::command]
+#[allow(clippy::too_many_arguments)]
+pub async fn hash_file(
+    app: AppHandle,
+    jobs: State<'_, HashJobs>,
+    path: String,
+    lowercase: bool,
+    algorithms: Option<Vec<String>>,
+    xof_bits: Option<usize>,
+    job_id: Option<String>,
+    encoding: Option<String>,
+) -> Result<HashMap<String, String>, String> {
+    let encoding = OutputEncoding::parse(encoding.as_deref(), lowercase)?;
+    let hashers = new_hashers(algorithms.as_deref(), xof_bits)?;
+    let cancelled = match &job_id {
+        Some(id) => jobs.register(id)?,
+        None => Arc::new(AtomicBool::new(false)),
+    };
+
+    let worker_job_id = job_id.clone();
+    let outcome = tauri::async_runtime::spawn_blocking(move || {
+        hash_file_with_progress(
+            &app,
+            &path,
+            hashers,
+            encoding,
+            worker_job_id,
+            &cancelled,
+        )
+    })
+    .await;
+
+    if let Some(id) = &job_id {
+        jobs.finish(id);
+    }
+
+    outcome.map_err(|e| format!("Hash job failed: {}", e))?
+}
+
+/// Re-renders lowercase hex digests from `hash_string` or `hash_file` in another
+/// encoding, so switching the output format doesn't hash the input again.
+#[tauri::command]
+pub fn encode_digests(
+    digests: HashMap<String, String>,
+    lowercase: bool,
+    encoding: Option<String>,
+) -> Result<HashMap<String, String>, String> {
+    let encoding = OutputEncoding::parse(encoding.as_deref(), lowercase)?;
+    let mut encoded = HashMap::new();
+    for (name, digest) in digests {
+        let bytes = hex::decode(digest.trim())
+            .map_err(|e| format!("Invalid hex digest for {}: {}", name, e))?;
+        if let Some(value) = encoding.encode(&name, &bytes) {
+            encoded.insert(name, value);
+        }
+    }
+    Ok(encoded)
+}
+
+/// Computes the S3 ETag of a file uploaded in parts of `part_size` bytes.
+///
+/// Multipart ETags are the MD5 of the concatenated part MD5s followed by
+/// `-<part count>`; a file that fits in one part gets its plain MD5, as a
+/// single `PUT` upload would.
+fn compute_s3_etag(path: &Path, part_size: u64) -> Result<String, String> {
+    if part_size == 0 {
+        return Err("Part size must be greater than zero".to_string());
+    }
+    let file = File::open(path).map_err(|e| format!("Failed to open file: {}", e))?;
+    let mut reader = io::BufReader::with_capacity(CHUNK_SIZE, file);
+    let mut buf = vec![0u8; CHUNK_SIZE];
+    let mut part_digests = Vec::new();
+
+    loop {
+        let mut part = Md5::new();
+        let mut part_len = 0u64;
+        while part_len < part_size {
+            let want = (part_size - part_len).min(CHUNK_SIZE as u64) as usize;
+            let n = read_chunk(&mut reader, &mut buf[..want])
+                .map_err(|e| format!("Failed to read file: {}", e))?;
+            if n == 0 {
+                break;
+            }
+            Digest::update(&mut part, &buf[..n]);
+            part_len += n as u64;
+        }
+        if part_len == 0 && !part_digests.is_empty() {
+            break;
+        }
+        part_digests.push(part.finalize().to_vec());
+        if part_len < part_size {
+            break;
+        }
+    }
+
+    if part_digests.len() == 1 {
+        return Ok(hex::encode(&part_digests[0]));
+    }
+    let combined = Md5::digest(part_digests.concat());
+    Ok(format!("{}-{}", hex::encode(combined), part_digests.len()))
+}
+
+#[tauri::command]
+pub async fn s3_etag(path: String, part_size: u64) -> Result<String, String> {
+    tauri::async_runtime::spawn_blocking(move || compute_s3_etag(Path::new(&path), part_size))
+        .await
+        .map_err(|e| format!("S3 ETag computation failed: {}", e))?
+}
+
+#[tauri::command]
+pub fn cancel_hash_job(jobs: State<'_, HashJobs>, job_id: String) -> bool {
+    jobs.cancel(&job_id)
+}
+
+/// Lists the CRC variants accepted as algorithm names, with their parameters.
+#[tauri::command]
+pub fn list_crc_variants() -> Vec<CrcVariant> {
+    let mut variants = Vec::new();
+    variants.extend(CRC16_CATALOG.iter().map(|(name, crc)| describe_crc(name, crc.algorithm)));
+    variants.extend(CRC32_CATALOG.iter().map(|(name, crc)| describe_crc(name, crc.algorithm)));
+    variants.extend(CRC64_CATALOG.iter().map(|(name, crc)| describe_crc(name, crc.algorithm)));
+    variants
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn algorithms(names: &[&str]) -> Option<Vec<String>> {
+        Some(names.iter().map(|name| name.to_string()).collect())
+    }
+
+    #[test]
+    fn sri_and_oci_omit_undefined_algorithms() {
+        let selected = algorithms(&["md5", "crc32", "xxh3-64", "sha256", "sha384", "sha512"]);
+
+        let sri = hash_string("abc", true, selected.clone(), None, Some("sri".into())).unwrap();
+        let mut names: Vec<_> = sri.keys().map(String::as_str).collect();
+        names.sort_unstable();
+        assert_eq!(names, ["sha256", "sha384", "sha512"]);
+        assert_eq!(sri["sha256"], "sha256-ungWv48Bz+pBQUDeXa4iI7ADYaOWF3qctBD/YfIAFa0=");
+
+        let oci = hash_string("abc", true, selected, None, Some("oci".into())).unwrap();
+        let mut names: Vec<_> = oci.keys().map(String::as_str).collect();
+        names.sort_unstable();
+        assert_eq!(names, ["sha256", "sha512"]);
+        assert!(oci["sha256"].starts_with("sha256:ba7816bf"));
+    }
+
+    #[test]
+    fn encode_digests_omits_undefined_algorithms() {
+        let hex = hash_string("abc", true, algorithms(&["md5", "sha256"]), None, None).unwrap();
+        let sri = encode_digests(hex, true, Some("sri".into())).unwrap();
+        assert_eq!(sri.len(), 1);
+        assert!(sri.contains_key("sha256"));
+    }
+}
@@ -0,0 +1,241 @@
+use base64::{
+    engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD},
+    Engine as _,
+};
+use hmac::{Hmac, Mac};
+use md5::Md5;
+use serde::{Deserialize, Serialize};
+use sha1::Sha1;
+use sha2::{Sha224, Sha256, Sha384, Sha512};
+use sha3::{Sha3_224, Sha3_256, Sha3_384, Sha3_512};
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HmacOptions {
+    pub algorithm: String,       // "md5", "sha1", "sha256", "sha3-256", ...
+    pub key_encoding: String,    // "utf8", "hex", "base64"
+    pub output_encoding: String, // "hex", "base64"
+    pub signature_format: Option<String>, // "github", "stripe", "slack"
+    pub timestamp: Option<String>, // Signed timestamp for the Stripe and Slack formats
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HmacVerifyResult {
+    is_valid: bool,
+    computed: String,
+}
+
+/// A signature to check against, reduced to raw bytes plus what was signed.
+struct ExpectedSignature {
+    candidates: Vec<Vec<u8>>,
+    timestamp: Option<String>,
+}
+
+fn decode_key(key: &str, encoding: &str) -> Result<Vec<u8>, String> {
+    match encoding {
+        "utf8" => Ok(key.as_bytes().to_vec()),
+        "hex" => {
+            let compact: String = key.split_whitespace().collect();
+            hex::decode(compact).map_err(|e| format!("Invalid hex key: {}", e))
+        }
+        "base64" => {
+            let compact: String = key.split_whitespace().collect();
+            STANDARD
+                .decode(&compact)
+                .or_else(|_| URL_SAFE_NO_PAD.decode(compact.trim_end_matches('=')))
+                .map_err(|e| format!("Invalid base64 key: {}", e))
+        }
+        _ => Err(format!("Unsupported key encoding: {}", encoding)),
+    }
+}
+
+fn encode_output(mac: &[u8], encoding: &str) -> Result<String, String> {
+    match encoding {
+        "hex" => Ok(hex::encode(mac)),
+        "base64" => Ok(STANDARD.encode(mac)),
+        _ => Err(format!("Unsupported output encoding: {}", encoding)),
+    }
+}
+
+fn decode_signature(signature: &str, encoding: &str) -> Result<Vec<u8>, String> {
+    let signature = signature.trim();
+    match encoding {
+        "hex" => hex::decode(signature).map_err(|e| format!("Invalid hex signature: {}", e)),
+        "base64" => STANDARD
+            .decode(signature)
+            .or_else(|_| URL_SAFE_NO_PAD.decode(signature.trim_end_matches('=')))
+            .map_err(|e| format!("Invalid base64 signature: {}", e)),
+        _ => Err(format!("Unsupported output encoding: {}", encoding)),
+    }
+}
+
+/// Builds the exact bytes a signing scheme feeds into the HMAC.
+fn signed_payload(
+    message: &str,
+    format: Option<&str>,
+    timestamp: Option<&str>,
+) -> Result<String, String> {
+    let require_timestamp = || {
+        timestamp
+            .filter(|ts| !ts.trim().is_empty())
+            .map(|ts| ts.trim())
+            .ok_or_else(|| "This signature format requires a timestamp".to_string())
+    };
+
+    match format {
+        None | Some("github") => Ok(message.to_string()),
+        Some("stripe") => Ok(format!("{}.{}", require_timestamp()?, message)),
+        Some("slack") => Ok(format!("v0:{}:{}", require_timestamp()?, message)),
+        Some(other) => Err(format!("Unsupported signature format: {}", other)),
+    }
+}
+
+/// Formats a MAC the way the given scheme transmits it in its header.
+fn format_signature(
+    mac: &[u8],
+    options: &HmacOptions,
+    timestamp: Option<&str>,
+) -> Result<String, String> {
+    match options.signature_format.as_deref() {
+        None => encode_output(mac, &options.output_encoding),
+        Some("github") => Ok(format!("{}={}", options.algorithm, hex::encode(mac))),
+        Some("stripe") => Ok(format!(
+            "t={},v1={}",
+            timestamp.unwrap_or_default(),
+            hex::encode(mac)
+        )),
+        Some("slack") => Ok(format!("v0={}", hex::encode(mac))),
+        Some(other) => Err(format!("Unsupported signature format: {}", other)),
+    }
+}
+
+/// Parses an expected signature or signature header according to the scheme.
+///
+/// The webhook formats always carry hex digests; plain signatures use `output_encoding`.
+fn parse_expected(expected: &str, options: &HmacOptions) -> Result<ExpectedSignature, String> {
+    let expected = expected.trim();
+    match options.signature_format.as_deref() {
+        None => Ok(ExpectedSignature {
+            candidates: vec![decode_signature(expected, &options.output_encoding)?],
+            timestamp: options.timestamp.clone(),
+        }),
+        Some("github") => {
+            let signature = match expected.split_once('=') {
+                Some((prefix, value)) => {
+                    if prefix != options.algorithm {
+                        return Err(format!(
+                            "Signature uses {} but {} was selected",
+                            prefix, options.algorithm
+                        ));
+                    }
+                    value
+                }
+                None => expected,
+            };
+            Ok(ExpectedSignature {
+                candidates: vec![decode_signature(signature, "hex")?],
+                timestamp: None,
+            })
+        }
+        Some("stripe") => {
+            // Stripe-Signature: t=<timestamp>,v1=<hex>[,v1=<hex>...]
+            let mut timestamp = options.timestamp.clone();
+            let mut candidates = Vec::new();
+            for part in expected.split(',') {
+                match part.trim().split_once('=') {
+                    Some(("t", value)) => timestamp = Some(value.to_string()),
+                    Some(("v1", value)) => candidates.push(decode_signature(value, "hex")?),
+                    _ => {}
+                }
+            }
+            if candidates.is_empty() {
+                return Err("No v1 signature found in Stripe signature header".to_string());
+            }
+            Ok(ExpectedSignature {
+                candidates,
+                timestamp,
+            })
+        }
+        Some("slack") => {
+            let signature = expected.strip_prefix("v0=").unwrap_or(expected);
+            Ok(ExpectedSignature {
+                candidates: vec![decode_signature(signature, "hex")?],
+                timestamp: options.timestamp.clone(),
+            })
+        }
+        Some(other) => Err(format!("Unsupported signature format: {}", other)),
+    }
+}
+
+/// Computes the MAC and checks it against each candidate in constant time.
+fn run_hmac<M: Mac + hmac::digest::KeyInit + Clone>(
+    key: &[u8],
+    payload: &[u8],
+    candidates: &[Vec<u8>],
+) -> (Vec<u8>, bool) {
+    let mut mac = <M as Mac>::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(payload);
+    let matched = candidates
+        .iter()
+        .any(|candidate| mac.clone().verify_slice(candidate).is_ok());
+    (mac.finalize().into_bytes().to_vec(), matched)
+}
+
+fn compute_hmac(
+    algorithm: &str,
+    key: &[u8],
+    payload: &[u8],
+    candidates: &[Vec<u8>],
+) -> Result<(Vec<u8>, bool), String> {
+    let outcome = match algorithm {
+        "md5" => run_hmac::<Hmac<Md5>>(key, payload, candidates),
+        "sha1" => run_hmac::<Hmac<Sha1>>(key, payload, candidates),
+        "sha224" => run_hmac::<Hmac<Sha224>>(key, payload, candidates),
+        "sha256" => run_hmac::<Hmac<Sha256>>(key, payload, candidates),
+        "sha384" => run_hmac::<Hmac<Sha384>>(key, payload, candidates),
+        "sha512" => run_hmac::<Hmac<Sha512>>(key, payload, candidates),
+        "sha3-224" => run_hmac::<Hmac<Sha3_224>>(key, payload, candidates),
+        "sha3-256" => run_hmac::<Hmac<Sha3_256>>(key, payload, candidates),
+        "sha3-384" => run_hmac::<Hmac<Sha3_384>>(key, payload, candidates),
+        "sha3-512" => run_hmac::<Hmac<Sha3_512>>(key, payload, candidates),
+        _ => return Err(format!("Unsupported HMAC algorithm: {}", algorithm)),
+    };
+    Ok(outcome)
+}
+
+#[tauri::command]
+pub fn generate_hmac(message: &str, key: &str, options: HmacOptions) -> Result<String, String> {
+    let key_bytes = decode_key(key, &options.key_encoding)?;
+    let timestamp = options.timestamp.as_deref();
+    let payload = signed_payload(message, options.signature_format.as_deref(), timestamp)?;
+
+    let (mac, _) = compute_hmac(&options.algorithm, &key_bytes, payload.as_bytes(), &[])?;
+    format_signature(&mac, &options, timestamp.map(str::trim))
+}
+
+#[tauri::command]
+pub fn verify_hmac(
+    message: &str,
+    key: &str,
+    expected: &str,
+    options: HmacOptions,
+) -> Result<HmacVerifyResult, String> {
+    let key_bytes = decode_key(key, &options.key_encoding)?;
+    let parsed = parse_expected(expected, &options)?;
+
+    let timestamp = parsed.timestamp.as_deref();
+    let payload = signed_payload(message, options.signature_format.as_deref(), timestamp)?;
+    let (mac, matched) = compute_hmac(
+        &options.algorithm,
+        &key_bytes,
+        payload.as_bytes(),
+        &parsed.candidates,
+    )?;
+    let computed = format_signature(&mac, &options, timestamp.map(str::trim))?;
+
+    Ok(HmacVerifyResult {
+        is_valid: matched,
+        computed,
+    })
+}
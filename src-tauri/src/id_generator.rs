@@ -0,0 +1,633 @@
+use chrono::{DateTime, SecondsFormat};
+use rand::{Rng, RngCore, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+use serde::{Deserialize, Serialize};
+use sha3::{Digest, Sha3_512};
+use std::time::{SystemTime, UNIX_EPOCH};
+use uuid::{Builder, ContextV7, Timestamp, Uuid};
+
+/// Crockford's base32 as used by ULID and TypeID (no I, L, O or U).
+const CROCKFORD_ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+const BASE62_ALPHABET: &[u8; 62] =
+    b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+const BASE36_ALPHABET: &[u8; 36] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+const NANOID_ALPHABET: &str = "useandom-26T198340PX75pxJACKVERYMINDBUSHWOLF_GQZbfghjklqvwyzrict";
+const NANOID_DEFAULT_LENGTH: usize = 21;
+const CUID2_DEFAULT_LENGTH: usize = 24;
+const CUID2_MAX_LENGTH: usize = 32;
+
+/// KSUID timestamps count seconds from 2014-05-13T16:53:20Z.
+const KSUID_EPOCH_SECONDS: i64 = 1_400_000_000;
+const KSUID_LENGTH: usize = 27;
+
+/// Twitter's Snowflake epoch (2010-11-04T01:42:54.657Z) in Unix milliseconds.
+const SNOWFLAKE_DEFAULT_EPOCH: i64 = 1_288_834_974_657;
+const SNOWFLAKE_DEFAULT_WORKER_BITS: u32 = 10;
+const SNOWFLAKE_DEFAULT_SEQUENCE_BITS: u32 = 12;
+
+/// Clock used by seeded runs without an explicit timestamp
+/// (2024-01-01T00:00:00Z), so their output never depends on the current time.
+const SEEDED_DEFAULT_MILLIS: i64 = 1_704_067_200_000;
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct IdOptions {
+    pub count: usize,
+    pub length: Option<usize>,      // NanoID and CUID2
+    pub alphabet: Option<String>,   // NanoID
+    pub prefix: Option<String>,     // TypeID
+    pub epoch: Option<i64>,         // Snowflake epoch in Unix ms
+    pub worker_bits: Option<u32>,   // Snowflake
+    pub sequence_bits: Option<u32>, // Snowflake
+    pub worker_id: Option<u64>,     // Snowflake
+    pub seed: Option<u64>,          // Reproducible output from a ChaCha20 PRNG
+    pub timestamp: Option<i64>,     // Fixed clock in Unix ms
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IdComponent {
+    pub name: String,
+    pub value: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ParsedId {
+    pub format: String,
+    pub timestamp: Option<String>, // RFC 3339
+    pub unix_millis: Option<i64>,
+    pub components: Vec<IdComponent>,
+}
+
+fn component(name: &str, value: impl ToString) -> IdComponent {
+    IdComponent {
+        name: name.to_string(),
+        value: value.to_string(),
+    }
+}
+
+fn now_millis() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as i64)
+        .unwrap_or(0)
+}
+
+/// The random source for a batch: a ChaCha20 stream when seeded, so the same
+/// seed always yields the same IDs, otherwise the thread-local OS-seeded RNG.
+pub(crate) fn id_rng(seed: Option<u64>) -> Box<dyn RngCore> {
+    match seed {
+        Some(seed) => Box::new(ChaCha20Rng::seed_from_u64(seed)),
+        None => Box::new(rand::thread_rng()),
+    }
+}
+
+/// Where generated IDs take their time from. Seeded runs are pinned to a
+/// fixed instant so time-based formats are reproducible too.
+#[derive(Clone, Copy)]
+pub(crate) struct IdClock(Option<i64>);
+
+impl IdClock {
+    pub(crate) fn new(seed: Option<u64>, timestamp: Option<i64>) -> Self {
+        IdClock(timestamp.or(seed.map(|_| SEEDED_DEFAULT_MILLIS)))
+    }
+
+    pub(crate) fn now_millis(&self) -> i64 {
+        self.0.unwrap_or_else(now_millis)
+    }
+
+    /// The current time as the unsigned 48-bit millisecond count that ULIDs
+    /// and v7 UUIDs store, rejecting fixed timestamps that don't fit.
+    pub(crate) fn now_millis_48(&self) -> Result<u64, String> {
+        let millis = self.now_millis();
+        u64::try_from(millis)
+            .ok()
+            .filter(|millis| millis >> 48 == 0)
+            .ok_or_else(|| {
+                format!(
+                    "Timestamp {} is outside the 48-bit millisecond range (0 to 2^48 - 1)",
+                    millis
+                )
+            })
+    }
+
+    pub(crate) fn is_fixed(&self) -> bool {
+        self.0.is_some()
+    }
+}
+
+fn format_millis(millis: i64) -> Option<String> {
+    DateTime::from_timestamp_millis(millis)
+        .map(|dt| dt.to_rfc3339_opts(SecondsFormat::Millis, true))
+}
+
+/// Encodes a big-endian unsigned integer with `alphabet`, most significant digit first.
+fn encode_base(bytes: &[u8], alphabet: &[u8]) -> String {
+    let base = alphabet.len() as u32;
+    let mut number = bytes.to_vec();
+    let mut digits = Vec::new();
+    while number.iter().any(|&b| b != 0) {
+        let mut remainder = 0u32;
+        for byte in number.iter_mut() {
+            let acc = (remainder << 8) | u32::from(*byte);
+            *byte = (acc / base) as u8;
+            remainder = acc % base;
+        }
+        digits.push(alphabet[remainder as usize] as char);
+    }
+    if digits.is_empty() {
+        digits.push(alphabet[0] as char);
+    }
+    digits.iter().rev().collect()
+}
+
+/// Decodes `text` written with `alphabet` into a `len`-byte big-endian integer.
+fn decode_base(text: &str, alphabet: &[u8], len: usize) -> Result<Vec<u8>, String> {
+    let base = alphabet.len() as u32;
+    let mut number = vec![0u8; len];
+    for c in text.bytes() {
+        let mut carry = alphabet
+            .iter()
+            .position(|&a| a == c)
+            .ok_or_else(|| format!("Invalid character '{}'", c as char))?
+            as u32;
+        for byte in number.iter_mut().rev() {
+            let acc = u32::from(*byte) * base + carry;
+            *byte = acc as u8;
+            carry = acc >> 8;
+        }
+        if carry != 0 {
+            return Err("Value is out of range".to_string());
+        }
+    }
+    Ok(number)
+}
+
+/// Encodes 128 bits as 26 Crockford base32 characters, as ULID and TypeID do.
+pub(crate) fn encode_crockford(value: u128) -> String {
+    (0..26)
+        .rev()
+        .map(|i| CROCKFORD_ALPHABET[((value >> (i * 5)) & 0x1F) as usize] as char)
+        .collect()
+}
+
+/// Decodes 26 Crockford base32 characters, case-insensitively, into 128 bits.
+pub(crate) fn decode_crockford(text: &str) -> Result<u128, String> {
+    if text.len() != 26 {
+        return Err("Expected 26 base32 characters".to_string());
+    }
+    let mut value = 0u128;
+    for (i, c) in text.chars().enumerate() {
+        let c = match c.to_ascii_uppercase() {
+            'I' | 'L' => '1',
+            'O' => '0',
+            c => c,
+        };
+        let digit = CROCKFORD_ALPHABET
+            .iter()
+            .position(|&a| a as char == c)
+            .ok_or_else(|| format!("Invalid base32 character '{}'", c))?;
+        // The first character only carries 3 bits
+        if i == 0 && digit > 7 {
+            return Err("Value exceeds 128 bits".to_string());
+        }
+        value = (value << 5) | digit as u128;
+    }
+    Ok(value)
+}
+
+/// Generates monotonic 48-bit millisecond + 80-bit random values: within the
+/// same millisecond the random part is incremented instead of redrawn, as the
+/// ULID spec describes.
+fn monotonic_values<R: Rng>(
+    rng: &mut R,
+    clock: IdClock,
+    count: usize,
+) -> Result<Vec<u128>, String> {
+    const RANDOM_MASK: u128 = (1 << 80) - 1;
+    let mut last: Option<(u64, u128)> = None;
+    let mut values = Vec::with_capacity(count);
+    for _ in 0..count {
+        let now = clock.now_millis_48()?;
+        let (millis, random) = match last {
+            Some((last_millis, last_random)) if now <= last_millis => {
+                if last_random == RANDOM_MASK {
+                    return Err("ULID random component overflowed".to_string());
+                }
+                (last_millis, last_random + 1)
+            }
+            _ => (now, rng.gen::<u128>() & RANDOM_MASK),
+        };
+        last = Some((millis, random));
+        values.push((u128::from(millis) << 80) | random);
+    }
+    Ok(values)
+}
+
+fn generate_ulids<R: Rng>(
+    rng: &mut R,
+    clock: IdClock,
+    count: usize,
+) -> Result<Vec<String>, String> {
+    Ok(monotonic_values(rng, clock, count)?
+        .into_iter()
+        .map(encode_crockford)
+        .collect())
+}
+
+/// Generates v7 UUIDs from `rng` and `clock`, kept in generation order the
+/// same way monotonic ULIDs are. Used when the output must be reproducible,
+/// since `ContextV7` draws its counter from the OS RNG.
+pub(crate) fn monotonic_v7s<R: Rng>(
+    rng: &mut R,
+    clock: IdClock,
+    count: usize,
+) -> Result<Vec<Uuid>, String> {
+    Ok(monotonic_values(rng, clock, count)?
+        .into_iter()
+        .map(|value| {
+            let random: [u8; 10] = value.to_be_bytes()[6..].try_into().unwrap();
+            Builder::from_unix_timestamp_millis((value >> 80) as u64, &random).into_uuid()
+        })
+        .collect())
+}
+
+fn parse_ulid(id: &str) -> Result<ParsedId, String> {
+    let value = decode_crockford(id).map_err(|e| format!("Invalid ULID: {}", e))?;
+    let millis = (value >> 80) as i64;
+    Ok(ParsedId {
+        format: "ulid".to_string(),
+        timestamp: format_millis(millis),
+        unix_millis: Some(millis),
+        components: vec![
+            component("randomness", format!("{:020x}", value & ((1 << 80) - 1))),
+            component("uuid", Uuid::from_u128(value).hyphenated()),
+        ],
+    })
+}
+
+fn generate_ksuids<R: Rng>(
+    rng: &mut R,
+    clock: IdClock,
+    count: usize,
+) -> Result<Vec<String>, String> {
+    let seconds = clock.now_millis() / 1000 - KSUID_EPOCH_SECONDS;
+    let seconds = u32::try_from(seconds).map_err(|_| "Clock is outside the KSUID range")?;
+    Ok((0..count)
+        .map(|_| {
+            let mut bytes = [0u8; 20];
+            bytes[..4].copy_from_slice(&seconds.to_be_bytes());
+            rng.fill(&mut bytes[4..]);
+            format!(
+                "{:0>width$}",
+                encode_base(&bytes, BASE62_ALPHABET),
+                width = KSUID_LENGTH
+            )
+        })
+        .collect())
+}
+
+fn parse_ksuid(id: &str) -> Result<ParsedId, String> {
+    if id.len() != KSUID_LENGTH {
+        return Err(format!(
+            "Invalid KSUID: expected {} characters",
+            KSUID_LENGTH
+        ));
+    }
+    let bytes =
+        decode_base(id, BASE62_ALPHABET, 20).map_err(|e| format!("Invalid KSUID: {}", e))?;
+    let seconds = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+    let millis = (i64::from(seconds) + KSUID_EPOCH_SECONDS) * 1000;
+    Ok(ParsedId {
+        format: "ksuid".to_string(),
+        timestamp: format_millis(millis),
+        unix_millis: Some(millis),
+        components: vec![
+            component("timestamp", seconds),
+            component("payload", hex::encode(&bytes[4..])),
+        ],
+    })
+}
+
+fn nanoid_alphabet(options: &IdOptions) -> Result<Vec<char>, String> {
+    let alphabet: Vec<char> = options
+        .alphabet
+        .as_deref()
+        .filter(|a| !a.is_empty())
+        .unwrap_or(NANOID_ALPHABET)
+        .chars()
+        .collect();
+    let mut unique = alphabet.clone();
+    unique.sort_unstable();
+    unique.dedup();
+    if unique.len() != alphabet.len() || !(2..=256).contains(&alphabet.len()) {
+        return Err("NanoID alphabet must have 2-256 distinct characters".to_string());
+    }
+    Ok(alphabet)
+}
+
+fn generate_nanoids<R: Rng>(rng: &mut R, options: &IdOptions) -> Result<Vec<String>, String> {
+    let alphabet = nanoid_alphabet(options)?;
+    let length = options.length.unwrap_or(NANOID_DEFAULT_LENGTH);
+    if length == 0 {
+        return Err("NanoID length must be greater than zero".to_string());
+    }
+    Ok((0..options.count)
+        .map(|_| {
+            (0..length)
+                .map(|_| alphabet[rng.gen_range(0..alphabet.len())])
+                .collect()
+        })
+        .collect())
+}
+
+fn parse_nanoid(id: &str, options: &IdOptions) -> Result<ParsedId, String> {
+    let alphabet = nanoid_alphabet(options)?;
+    if let Some(c) = id.chars().find(|c| !alphabet.contains(c)) {
+        return Err(format!("Invalid NanoID: '{}' is not in the alphabet", c));
+    }
+    let length = id.chars().count();
+    let entropy = length as f64 * (alphabet.len() as f64).log2();
+    Ok(ParsedId {
+        format: "nanoid".to_string(),
+        timestamp: None,
+        unix_millis: None,
+        components: vec![
+            component("length", length),
+            component("alphabetSize", alphabet.len()),
+            component("entropyBits", format!("{:.1}", entropy)),
+        ],
+    })
+}
+
+fn random_base36<R: Rng>(rng: &mut R, length: usize) -> String {
+    (0..length)
+        .map(|_| BASE36_ALPHABET[rng.gen_range(0..36)] as char)
+        .collect()
+}
+
+/// Hashes `input` with SHA3-512 and renders it in base36 without the first
+/// (biased) digit, as the reference CUID2 implementation does.
+fn cuid2_hash(input: &str) -> String {
+    let digest = Sha3_512::digest(input.as_bytes());
+    encode_base(&digest, BASE36_ALPHABET)[1..].to_string()
+}
+
+/// CUID2: a random letter followed by a hash of the time, a counter, random
+/// salt and a per-batch fingerprint. The result carries no readable fields.
+fn generate_cuid2s<R: Rng>(
+    rng: &mut R,
+    clock: IdClock,
+    options: &IdOptions,
+) -> Result<Vec<String>, String> {
+    let length = options.length.unwrap_or(CUID2_DEFAULT_LENGTH);
+    if !(2..=CUID2_MAX_LENGTH).contains(&length) {
+        return Err(format!("CUID2 length must be 2-{}", CUID2_MAX_LENGTH));
+    }
+    let fingerprint = cuid2_hash(&random_base36(rng, 32));
+    let mut counter: u64 = rng.gen_range(0..476_782_367);
+    Ok((0..options.count)
+        .map(|_| {
+            counter += 1;
+            let first_letter = BASE36_ALPHABET[rng.gen_range(10..36)] as char;
+            let input = format!(
+                "{}{}{}{}",
+                encode_base(&clock.now_millis().to_be_bytes(), BASE36_ALPHABET),
+                random_base36(rng, length),
+                encode_base(&counter.to_be_bytes(), BASE36_ALPHABET),
+                fingerprint
+            );
+            let hash = cuid2_hash(&input);
+            format!("{}{}", first_letter, &hash[1..length])
+        })
+        .collect())
+}
+
+fn parse_cuid2(id: &str) -> Result<ParsedId, String> {
+    let valid = id.starts_with(|c: char| c.is_ascii_lowercase())
+        && id
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+        && (2..=CUID2_MAX_LENGTH).contains(&id.len());
+    if !valid {
+        return Err(
+            "Invalid CUID2: expected a lowercase letter followed by base36 characters".to_string(),
+        );
+    }
+    Ok(ParsedId {
+        format: "cuid2".to_string(),
+        timestamp: None,
+        unix_millis: None,
+        components: vec![component("length", id.len())],
+    })
+}
+
+/// TypeID prefixes: up to 63 lowercase letters or underscores, not starting
+/// or ending with an underscore.
+fn validate_typeid_prefix(prefix: &str) -> Result<(), String> {
+    let valid = prefix.len() <= 63
+        && prefix.chars().all(|c| c.is_ascii_lowercase() || c == '_')
+        && !prefix.starts_with('_')
+        && !prefix.ends_with('_');
+    if valid {
+        Ok(())
+    } else {
+        Err(format!("Invalid TypeID prefix: {}", prefix))
+    }
+}
+
+fn generate_typeids<R: Rng>(
+    rng: &mut R,
+    clock: IdClock,
+    options: &IdOptions,
+) -> Result<Vec<String>, String> {
+    let prefix = options.prefix.as_deref().unwrap_or("").trim();
+    validate_typeid_prefix(prefix)?;
+    let uuids = if clock.is_fixed() {
+        monotonic_v7s(rng, clock, options.count)?
+    } else {
+        let context = ContextV7::new();
+        (0..options.count)
+            .map(|_| Uuid::new_v7(Timestamp::now(&context)))
+            .collect()
+    };
+    Ok(uuids
+        .into_iter()
+        .map(|uuid| {
+            let suffix = encode_crockford(uuid.as_u128()).to_lowercase();
+            if prefix.is_empty() {
+                suffix
+            } else {
+                format!("{}_{}", prefix, suffix)
+            }
+        })
+        .collect())
+}
+
+fn parse_typeid(id: &str) -> Result<ParsedId, String> {
+    let (prefix, suffix) = id.rsplit_once('_').unwrap_or(("", id));
+    validate_typeid_prefix(prefix)?;
+    if suffix.chars().any(|c| c.is_ascii_uppercase()) {
+        return Err("Invalid TypeID: the suffix must be lowercase".to_string());
+    }
+    let uuid =
+        Uuid::from_u128(decode_crockford(suffix).map_err(|e| format!("Invalid TypeID: {}", e))?);
+    let millis = (uuid.get_version_num() == 7).then(|| (uuid.as_u128() >> 80) as i64);
+    Ok(ParsedId {
+        format: "typeid".to_string(),
+        timestamp: millis.and_then(format_millis),
+        unix_millis: millis,
+        components: vec![
+            component("prefix", prefix),
+            component("uuid", uuid.hyphenated()),
+            component("uuidVersion", uuid.get_version_num()),
+        ],
+    })
+}
+
+/// Bit layout of a Snowflake ID: sign bit, timestamp, worker, sequence.
+struct SnowflakeLayout {
+    epoch: i64,
+    worker_bits: u32,
+    sequence_bits: u32,
+}
+
+impl SnowflakeLayout {
+    fn from_options(options: &IdOptions) -> Result<Self, String> {
+        let layout = SnowflakeLayout {
+            epoch: options.epoch.unwrap_or(SNOWFLAKE_DEFAULT_EPOCH),
+            worker_bits: options.worker_bits.unwrap_or(SNOWFLAKE_DEFAULT_WORKER_BITS),
+            sequence_bits: options
+                .sequence_bits
+                .unwrap_or(SNOWFLAKE_DEFAULT_SEQUENCE_BITS),
+        };
+        let total_bits = layout.worker_bits.checked_add(layout.sequence_bits);
+        if total_bits.filter(|&bits| bits <= 32).is_none() {
+            return Err("Worker and sequence bits may not exceed 32 in total".to_string());
+        }
+        Ok(layout)
+    }
+
+    fn timestamp_bits(&self) -> u32 {
+        63 - self.worker_bits - self.sequence_bits
+    }
+}
+
+fn generate_snowflakes(clock: IdClock, options: &IdOptions) -> Result<Vec<String>, String> {
+    let layout = SnowflakeLayout::from_options(options)?;
+    let worker_id = options.worker_id.unwrap_or(0);
+    if worker_id >> layout.worker_bits != 0 {
+        return Err(format!("Worker ID must fit in {} bits", layout.worker_bits));
+    }
+    let max_sequence = (1u64 << layout.sequence_bits) - 1;
+
+    let mut last_elapsed = -1i64;
+    let mut sequence = 0u64;
+    let mut ids = Vec::with_capacity(options.count);
+    for _ in 0..options.count {
+        let mut elapsed = clock
+            .now_millis()
+            .checked_sub(layout.epoch)
+            .ok_or("Current time is outside the range of this Snowflake epoch")?
+            .max(last_elapsed);
+        if elapsed == last_elapsed {
+            // Move on to the next millisecond once the sequence runs out
+            if sequence == max_sequence {
+                elapsed += 1;
+                sequence = 0;
+            } else {
+                sequence += 1;
+            }
+        } else {
+            sequence = 0;
+        }
+        if elapsed < 0 || (elapsed as u64) >> layout.timestamp_bits() != 0 {
+            return Err("Current time is outside the range of this Snowflake epoch".to_string());
+        }
+        last_elapsed = elapsed;
+        let id = ((elapsed as u64) << (layout.worker_bits + layout.sequence_bits))
+            | (worker_id << layout.sequence_bits)
+            | sequence;
+        ids.push(id.to_string());
+    }
+    Ok(ids)
+}
+
+fn parse_snowflake(id: &str, options: &IdOptions) -> Result<ParsedId, String> {
+    let layout = SnowflakeLayout::from_options(options)?;
+    let value: u64 = id
+        .parse()
+        .map_err(|e| format!("Invalid Snowflake ID: {}", e))?;
+    let elapsed = value >> (layout.worker_bits + layout.sequence_bits);
+    let worker = (value >> layout.sequence_bits) & ((1u64 << layout.worker_bits) - 1);
+    let sequence = value & ((1u64 << layout.sequence_bits) - 1);
+    let millis = i64::try_from(elapsed)
+        .ok()
+        .and_then(|elapsed| layout.epoch.checked_add(elapsed))
+        .ok_or("Snowflake timestamp overflows with this epoch")?;
+    Ok(ParsedId {
+        format: "snowflake".to_string(),
+        timestamp: format_millis(millis),
+        unix_millis: Some(millis),
+        components: vec![
+            component("worker", worker),
+            component("sequence", sequence),
+            component("epoch", layout.epoch),
+        ],
+    })
+}
+
+/// Generates IDs in one of the non-UUID formats: `ulid`, `ksuid`, `nanoid`,
+/// `cuid2`, `typeid` or `snowflake`. With a `seed` the same options always
+/// produce the same IDs.
+#[tauri::command]
+pub fn generate_ids(format: String, options: IdOptions) -> Result<Vec<String>, String> {
+    let mut rng = id_rng(options.seed);
+    let clock = IdClock::new(options.seed, options.timestamp);
+    match format.as_str() {
+        "ulid" => generate_ulids(&mut rng, clock, options.count),
+        "ksuid" => generate_ksuids(&mut rng, clock, options.count),
+        "nanoid" => generate_nanoids(&mut rng, &options),
+        "cuid2" => generate_cuid2s(&mut rng, clock, &options),
+        "typeid" => generate_typeids(&mut rng, clock, &options),
+        "snowflake" => generate_snowflakes(clock, &options),
+        _ => Err(format!("Unsupported ID format: {}", format)),
+    }
+}
+
+/// Parses an ID in the given format, reporting its timestamp and components.
+/// NanoID and Snowflake use `options` for their alphabet and bit layout.
+#[tauri::command]
+pub fn parse_id(
+    format: String,
+    id: String,
+    options: Option<IdOptions>,
+) -> Result<ParsedId, String> {
+    let id = id.trim();
+    let options = options.unwrap_or_default();
+    match format.as_str() {
+        "ulid" => parse_ulid(id),
+        "ksuid" => parse_ksuid(id),
+        "nanoid" => parse_nanoid(id, &options),
+        "cuid2" => parse_cuid2(id),
+        "typeid" => parse_typeid(id),
+        "snowflake" => parse_snowflake(id, &options),
+        _ => Err(format!("Unsupported ID format: {}", format)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_snowflake_bits_that_overflow() {
+        let options = IdOptions {
+            worker_bits: Some(u32::MAX),
+            sequence_bits: Some(12),
+            ..Default::default()
+        };
+        assert!(SnowflakeLayout::from_options(&options).is_err());
+    }
+}
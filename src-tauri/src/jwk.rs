@@ -0,0 +1,450 @@
+use crate::jwt_keys::{DetectedKey, Key, KeyKind, PrivateKey, PublicKey};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+use jsonwebtoken::Algorithm;
+use p256::elliptic_curve::sec1::ToEncodedPoint;
+use rsa::traits::{PrivateKeyParts, PublicKeyParts};
+use rsa::{BigUint, RsaPrivateKey, RsaPublicKey};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+/// A JSON Web Key (RFC 7517) holding the members used by RSA, EC, OKP and
+/// `oct` keys. Other members, such as `x5c` or `key_ops`, are ignored.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct Jwk {
+    pub kty: String,
+    #[serde(rename = "use", skip_serializing_if = "Option::is_none")]
+    pub key_use: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alg: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kid: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub crv: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub n: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub e: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub x: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub y: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub d: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub p: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub q: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dp: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dq: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub qi: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub k: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct JwkSet {
+    keys: Vec<Jwk>,
+}
+
+/// A key from a JWKS that can verify the token being checked.
+pub(crate) struct JwksCandidate {
+    pub index: usize,
+    pub jwk: Jwk,
+    pub key: PublicKey,
+}
+
+/// An `oct` key from a JWKS that can verify an HMAC token.
+pub(crate) struct SecretCandidate {
+    pub index: usize,
+    pub jwk: Jwk,
+    pub secret: Vec<u8>,
+}
+
+fn encode(bytes: &[u8]) -> Option<String> {
+    Some(URL_SAFE_NO_PAD.encode(bytes))
+}
+
+fn encode_uint(value: &BigUint) -> Option<String> {
+    encode(&value.to_bytes_be())
+}
+
+impl Jwk {
+    fn member(&self, name: &str) -> Result<Vec<u8>, String> {
+        let value = match name {
+            "n" => &self.n,
+            "e" => &self.e,
+            "x" => &self.x,
+            "y" => &self.y,
+            "d" => &self.d,
+            "p" => &self.p,
+            "q" => &self.q,
+            "k" => &self.k,
+            _ => &None,
+        };
+        let value = value
+            .as_deref()
+            .ok_or_else(|| format!("JWK is missing \"{}\"", name))?;
+        // Some encoders pad or use the standard alphabet despite RFC 7515
+        let value = value
+            .trim_end_matches('=')
+            .replace('+', "-")
+            .replace('/', "_");
+        URL_SAFE_NO_PAD
+            .decode(value)
+            .map_err(|e| format!("Invalid base64url in JWK \"{}\": {}", name, e))
+    }
+
+    fn uint(&self, name: &str) -> Result<BigUint, String> {
+        Ok(BigUint::from_bytes_be(&self.member(name)?))
+    }
+
+    fn kind(&self) -> Result<KeyKind, String> {
+        match (self.kty.as_str(), self.crv.as_deref()) {
+            ("RSA", _) => Ok(KeyKind::Rsa),
+            ("EC", Some("P-256")) => Ok(KeyKind::P256),
+            ("EC", Some("P-384")) => Ok(KeyKind::P384),
+            ("OKP", Some("Ed25519")) => Ok(KeyKind::Ed25519),
+            ("EC", crv) | ("OKP", crv) => Err(format!(
+                "Unsupported JWK curve: {}",
+                crv.unwrap_or("(missing)")
+            )),
+            (kty, _) => Err(format!("Unsupported JWK key type: {}", kty)),
+        }
+    }
+
+    /// The uncompressed SEC1 point for an EC key, `0x04 || x || y`.
+    fn ec_point(&self, size: usize) -> Result<Vec<u8>, String> {
+        let (x, y) = (self.member("x")?, self.member("y")?);
+        if x.len() != size || y.len() != size {
+            return Err(format!(
+                "JWK coordinates for {} must be {} bytes",
+                self.crv.as_deref().unwrap_or_default(),
+                size
+            ));
+        }
+        Ok([&[0x04][..], &x, &y].concat())
+    }
+
+    /// Whether this key may verify `alg` tokens: `use` and `alg` must allow
+    /// it when present.
+    fn allows(&self, alg: &str) -> bool {
+        self.key_use.as_deref().is_none_or(|u| u == "sig")
+            && self.alg.as_deref().is_none_or(|a| a == alg)
+    }
+
+    /// The RFC 7638 thumbprint of an `oct` key.
+    pub(crate) fn secret_thumbprint(&self) -> String {
+        let canonical = format!(
+            r#"{{"k":"{}","kty":"oct"}}"#,
+            self.k.as_deref().unwrap_or_default()
+        );
+        URL_SAFE_NO_PAD.encode(Sha256::digest(canonical.as_bytes()))
+    }
+
+    pub(crate) fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string_pretty(self).map_err(|e| format!("Failed to serialize JWK: {}", e))
+    }
+}
+
+impl PublicKey {
+    pub(crate) fn from_jwk(jwk: &Jwk) -> Result<Self, String> {
+        let invalid = |e: &dyn std::fmt::Display| format!("Invalid {} JWK: {}", jwk.kty, e);
+        match jwk.kind()? {
+            KeyKind::Rsa => RsaPublicKey::new(jwk.uint("n")?, jwk.uint("e")?)
+                .map(PublicKey::Rsa)
+                .map_err(|e| invalid(&e)),
+            KeyKind::P256 => p256::PublicKey::from_sec1_bytes(&jwk.ec_point(32)?)
+                .map(PublicKey::P256)
+                .map_err(|e| invalid(&e)),
+            KeyKind::P384 => p384::PublicKey::from_sec1_bytes(&jwk.ec_point(48)?)
+                .map(PublicKey::P384)
+                .map_err(|e| invalid(&e)),
+            KeyKind::Ed25519 => {
+                let x: [u8; 32] = jwk
+                    .member("x")?
+                    .try_into()
+                    .map_err(|_| "Ed25519 JWK \"x\" must be 32 bytes".to_string())?;
+                ed25519_dalek::VerifyingKey::from_bytes(&x)
+                    .map(PublicKey::Ed25519)
+                    .map_err(|e| invalid(&e))
+            }
+        }
+    }
+
+    pub(crate) fn to_jwk(&self) -> Jwk {
+        match self {
+            PublicKey::Rsa(key) => Jwk {
+                kty: "RSA".to_string(),
+                n: encode_uint(key.n()),
+                e: encode_uint(key.e()),
+                ..Default::default()
+            },
+            PublicKey::P256(key) => {
+                let point = key.to_encoded_point(false);
+                ec_jwk("P-256", point.x(), point.y())
+            }
+            PublicKey::P384(key) => {
+                let point = key.to_encoded_point(false);
+                ec_jwk("P-384", point.x(), point.y())
+            }
+            PublicKey::Ed25519(key) => Jwk {
+                kty: "OKP".to_string(),
+                crv: Some("Ed25519".to_string()),
+                x: encode(key.as_bytes()),
+                ..Default::default()
+            },
+        }
+    }
+
+    /// The RFC 7638 thumbprint: the base64url SHA-256 of the required
+    /// members, in lexicographic order with no whitespace.
+    pub(crate) fn thumbprint(&self) -> String {
+        let jwk = self.to_jwk();
+        let member = |value: &Option<String>| value.clone().unwrap_or_default();
+        let canonical = match self {
+            PublicKey::Rsa(_) => format!(
+                r#"{{"e":"{}","kty":"RSA","n":"{}"}}"#,
+                member(&jwk.e),
+                member(&jwk.n)
+            ),
+            PublicKey::P256(_) | PublicKey::P384(_) => format!(
+                r#"{{"crv":"{}","kty":"EC","x":"{}","y":"{}"}}"#,
+                member(&jwk.crv),
+                member(&jwk.x),
+                member(&jwk.y)
+            ),
+            PublicKey::Ed25519(_) => format!(
+                r#"{{"crv":"Ed25519","kty":"OKP","x":"{}"}}"#,
+                member(&jwk.x)
+            ),
+        };
+        URL_SAFE_NO_PAD.encode(Sha256::digest(canonical.as_bytes()))
+    }
+}
+
+fn ec_jwk(crv: &str, x: Option<&impl AsRef<[u8]>>, y: Option<&impl AsRef<[u8]>>) -> Jwk {
+    Jwk {
+        kty: "EC".to_string(),
+        crv: Some(crv.to_string()),
+        x: x.and_then(|x| encode(x.as_ref())),
+        y: y.and_then(|y| encode(y.as_ref())),
+        ..Default::default()
+    }
+}
+
+impl PrivateKey {
+    /// Parses a private JWK. RSA keys without `p` and `q` have their primes
+    /// recovered from `n`, `e` and `d`.
+    pub(crate) fn from_jwk(jwk: &Jwk) -> Result<Self, String> {
+        let invalid = |e: &dyn std::fmt::Display| format!("Invalid {} JWK: {}", jwk.kty, e);
+        let key = match jwk.kind()? {
+            KeyKind::Rsa => {
+                let primes = match (&jwk.p, &jwk.q) {
+                    (Some(_), Some(_)) => vec![jwk.uint("p")?, jwk.uint("q")?],
+                    _ => Vec::new(),
+                };
+                RsaPrivateKey::from_components(
+                    jwk.uint("n")?,
+                    jwk.uint("e")?,
+                    jwk.uint("d")?,
+                    primes,
+                )
+                .map(PrivateKey::Rsa)
+                .map_err(|e| invalid(&e))?
+            }
+            KeyKind::P256 => p256::SecretKey::from_slice(&jwk.member("d")?)
+                .map(PrivateKey::P256)
+                .map_err(|e| invalid(&e))?,
+            KeyKind::P384 => p384::SecretKey::from_slice(&jwk.member("d")?)
+                .map(PrivateKey::P384)
+                .map_err(|e| invalid(&e))?,
+            KeyKind::Ed25519 => {
+                let d: [u8; 32] = jwk
+                    .member("d")?
+                    .try_into()
+                    .map_err(|_| "Ed25519 JWK \"d\" must be 32 bytes".to_string())?;
+                PrivateKey::Ed25519(ed25519_dalek::SigningKey::from_bytes(&d))
+            }
+        };
+        // The public members are redundant for EC and OKP keys; make sure
+        // they describe the same key rather than silently ignoring them
+        let derived = key.public_key().to_jwk();
+        if jwk.x.is_some() && (jwk.x != derived.x || jwk.y != derived.y) {
+            return Err("JWK \"d\" does not match its public key".to_string());
+        }
+        Ok(key)
+    }
+
+    pub(crate) fn to_jwk(&self) -> Result<Jwk, String> {
+        let mut jwk = self.public_key().to_jwk();
+        match self {
+            PrivateKey::Rsa(key) => {
+                let [p, q] = key.primes() else {
+                    return Err("Multi-prime RSA keys can't be written as a JWK".to_string());
+                };
+                let one = BigUint::from(1u8);
+                jwk.d = encode_uint(key.d());
+                jwk.p = encode_uint(p);
+                jwk.q = encode_uint(q);
+                jwk.dp = encode_uint(&(key.d() % (p - &one)));
+                jwk.dq = encode_uint(&(key.d() % (q - &one)));
+                jwk.qi = key.crt_coefficient().as_ref().and_then(encode_uint);
+            }
+            PrivateKey::P256(key) => jwk.d = encode(&key.to_bytes()),
+            PrivateKey::P384(key) => jwk.d = encode(&key.to_bytes()),
+            PrivateKey::Ed25519(key) => jwk.d = encode(&key.to_bytes()),
+        }
+        Ok(jwk)
+    }
+}
+
+/// Whether a pasted key looks like JSON rather than PEM or base64 DER.
+pub(crate) fn is_json(input: &str) -> bool {
+    input.trim_start().starts_with('{')
+}
+
+/// Parses a JWKS (`{"keys": [...]}`) or a single JWK into a list of keys.
+pub(crate) fn parse_jwks(input: &str) -> Result<Vec<Jwk>, String> {
+    let value: Value =
+        serde_json::from_str(input).map_err(|e| format!("Failed to parse JWK JSON: {}", e))?;
+    jwks_from_value(value)
+}
+
+/// Like `parse_jwks`, for JSON that has already been parsed.
+pub(crate) fn jwks_from_value(value: Value) -> Result<Vec<Jwk>, String> {
+    if value.get("keys").is_some() {
+        let set: JwkSet =
+            serde_json::from_value(value).map_err(|e| format!("Invalid JWKS: {}", e))?;
+        Ok(set.keys)
+    } else if value.get("kty").is_some() {
+        let jwk = serde_json::from_value(value).map_err(|e| format!("Invalid JWK: {}", e))?;
+        Ok(vec![jwk])
+    } else {
+        Err("Expected a JWK with \"kty\" or a JWKS with a \"keys\" array".to_string())
+    }
+}
+
+/// Reads the secret of a single `oct` JWK for signing HMAC tokens. A JWKS
+/// is accepted when it holds exactly one key.
+pub(crate) fn parse_secret_jwk(input: &str) -> Result<Vec<u8>, String> {
+    let keys = parse_jwks(input)?;
+    match keys.as_slice() {
+        [jwk] if jwk.kty == "oct" => jwk.member("k"),
+        [jwk] => Err(format!("HMAC algorithms need an oct JWK, got {}", jwk.kty)),
+        _ => Err(format!(
+            "This JWKS holds {} keys; paste a single JWK",
+            keys.len()
+        )),
+    }
+}
+
+/// Parses a single JWK for `parse_key`. A JWKS is accepted when it holds
+/// exactly one key.
+pub(crate) fn parse_jwk_key(input: &str) -> Result<DetectedKey, String> {
+    let mut keys = parse_jwks(input)?;
+    if keys.len() != 1 {
+        return Err(format!(
+            "This JWKS holds {} keys; paste a single JWK",
+            keys.len()
+        ));
+    }
+    let jwk = keys.remove(0);
+    if jwk.d.is_some() {
+        Ok(DetectedKey {
+            key: Key::Private(PrivateKey::from_jwk(&jwk)?),
+            format: "JWK private key".to_string(),
+        })
+    } else {
+        Ok(DetectedKey {
+            key: Key::Public(PublicKey::from_jwk(&jwk)?),
+            format: "JWK public key".to_string(),
+        })
+    }
+}
+
+/// Picks the keys in a JWKS that could have signed a token with the given
+/// header `kid` and algorithm. Keys with a different `kid`, a different
+/// `alg`, a `use` other than `sig` or the wrong key type are skipped; keys
+/// without a `kid` remain candidates, in document order.
+pub(crate) fn select_keys(
+    keys: &[Jwk],
+    kid: Option<&str>,
+    alg: Algorithm,
+) -> Result<Vec<JwksCandidate>, String> {
+    let alg_name = format!("{:?}", alg);
+    let kind = KeyKind::for_algorithm(alg)
+        .ok_or_else(|| format!("A JWKS can't verify {} tokens", alg_name))?;
+
+    let mut candidates = Vec::new();
+    for (index, jwk) in keys.iter().enumerate() {
+        if kid.is_some() && jwk.kid.is_some() && jwk.kid.as_deref() != kid {
+            continue;
+        }
+        if !jwk.allows(&alg_name) || jwk.kind() != Ok(kind) {
+            continue;
+        }
+        // A key that names this token's kid but can't be read is worth
+        // reporting; anything else that fails to parse is just skipped
+        match PublicKey::from_jwk(jwk) {
+            Ok(key) => candidates.push(JwksCandidate {
+                index,
+                jwk: jwk.clone(),
+                key,
+            }),
+            Err(e) if kid.is_some() && jwk.kid.as_deref() == kid => return Err(e),
+            Err(_) => {}
+        }
+    }
+
+    if candidates.is_empty() {
+        return Err(no_matching_key(kid, &alg_name));
+    }
+    Ok(candidates)
+}
+
+/// Like `select_keys`, for HMAC tokens: picks the `oct` keys and decodes
+/// their base64url `k` secrets.
+pub(crate) fn select_secrets(
+    keys: &[Jwk],
+    kid: Option<&str>,
+    alg: Algorithm,
+) -> Result<Vec<SecretCandidate>, String> {
+    let alg_name = format!("{:?}", alg);
+    let mut candidates = Vec::new();
+    for (index, jwk) in keys.iter().enumerate() {
+        if kid.is_some() && jwk.kid.is_some() && jwk.kid.as_deref() != kid {
+            continue;
+        }
+        if jwk.kty != "oct" || !jwk.allows(&alg_name) {
+            continue;
+        }
+        match jwk.member("k") {
+            Ok(secret) => candidates.push(SecretCandidate {
+                index,
+                jwk: jwk.clone(),
+                secret,
+            }),
+            Err(e) if kid.is_some() && jwk.kid.as_deref() == kid => return Err(e),
+            Err(_) => {}
+        }
+    }
+
+    if candidates.is_empty() {
+        return Err(no_matching_key(kid, &alg_name));
+    }
+    Ok(candidates)
+}
+
+fn no_matching_key(kid: Option<&str>, alg_name: &str) -> String {
+    match kid {
+        Some(kid) => format!(
+            "No key in the JWKS matches kid \"{}\" and alg {}",
+            kid, alg_name
+        ),
+        None => format!("No key in the JWKS can verify {} tokens", alg_name),
+    }
+}
@@ -0,0 +1,408 @@
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey};
+use p256::elliptic_curve::sec1::ToEncodedPoint;
+use rsa::pkcs1::{
+    DecodeRsaPrivateKey, DecodeRsaPublicKey, EncodeRsaPrivateKey, EncodeRsaPublicKey,
+};
+use rsa::pkcs8::{
+    DecodePrivateKey, DecodePublicKey, EncodePrivateKey, EncodePublicKey, LineEnding,
+};
+use rsa::{RsaPrivateKey, RsaPublicKey};
+use x509_cert::der::{Decode, Encode};
+use x509_cert::Certificate;
+
+/// The kind of asymmetric key an algorithm signs with.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum KeyKind {
+    Rsa,
+    P256,
+    P384,
+    Ed25519,
+}
+
+impl KeyKind {
+    /// The key kind `algorithm` needs, or `None` for HMAC.
+    pub(crate) fn for_algorithm(algorithm: Algorithm) -> Option<KeyKind> {
+        match algorithm {
+            Algorithm::HS256 | Algorithm::HS384 | Algorithm::HS512 => None,
+            Algorithm::ES256 => Some(KeyKind::P256),
+            Algorithm::ES384 => Some(KeyKind::P384),
+            Algorithm::EdDSA => Some(KeyKind::Ed25519),
+            _ => Some(KeyKind::Rsa),
+        }
+    }
+
+    pub(crate) fn name(&self) -> &'static str {
+        match self {
+            KeyKind::Rsa => "RSA",
+            KeyKind::P256 => "P-256",
+            KeyKind::P384 => "P-384",
+            KeyKind::Ed25519 => "Ed25519",
+        }
+    }
+}
+
+pub(crate) enum PrivateKey {
+    Rsa(RsaPrivateKey),
+    P256(p256::SecretKey),
+    P384(p384::SecretKey),
+    Ed25519(ed25519_dalek::SigningKey),
+}
+
+pub(crate) enum PublicKey {
+    Rsa(RsaPublicKey),
+    P256(p256::PublicKey),
+    P384(p384::PublicKey),
+    Ed25519(ed25519_dalek::VerifyingKey),
+}
+
+/// Either half of a key pair, as found in the input.
+pub(crate) enum Key {
+    Private(PrivateKey),
+    Public(PublicKey),
+}
+
+/// A parsed key together with the encoding it was detected as.
+pub(crate) struct DetectedKey {
+    pub key: Key,
+    pub format: String,
+}
+
+/// Returns the first PEM block holding a key or certificate. OpenSSL puts
+/// an `EC PARAMETERS` block before `EC PRIVATE KEY` and certificate chains
+/// hold several blocks, so the input can't be decoded as a single document.
+fn first_pem_block(input: &str) -> Option<&str> {
+    let mut rest = input;
+    while let Some(start) = rest.find("-----BEGIN ") {
+        let block = &rest[start..];
+        let end_marker = block.find("-----END ")?;
+        let end = block[end_marker..]
+            .find("\n")
+            .map_or(block.len(), |i| end_marker + i);
+        if !block.starts_with("-----BEGIN EC PARAMETERS-----") {
+            return Some(&block[..end]);
+        }
+        rest = &block[end..];
+    }
+    None
+}
+
+fn pkcs1_private(der: &[u8]) -> Result<PrivateKey, String> {
+    RsaPrivateKey::from_pkcs1_der(der)
+        .map(PrivateKey::Rsa)
+        .map_err(|e| format!("Failed to parse RSA private key: {}", e))
+}
+
+/// Decrypts a PBES2 `EncryptedPrivateKeyInfo`, as written by
+/// `openssl pkcs8 -topk8` or `openssl genpkey -aes256`.
+fn encrypted_pkcs8(der: &[u8], passphrase: Option<&str>) -> Result<PrivateKey, String> {
+    let info = pkcs8::EncryptedPrivateKeyInfo::try_from(der)
+        .map_err(|e| format!("Invalid encrypted private key: {}", e))?;
+    let passphrase = passphrase
+        .filter(|p| !p.is_empty())
+        .ok_or("The private key is encrypted; enter its passphrase")?;
+    let document = info
+        .decrypt(passphrase)
+        .map_err(|e| format!("Failed to decrypt private key (wrong passphrase?): {}", e))?;
+    PrivateKey::from_pkcs8_der(document.as_bytes())
+}
+
+/// SEC1 `ECPrivateKey`, OpenSSL's traditional `EC PRIVATE KEY` format.
+fn sec1_private(der: &[u8]) -> Result<PrivateKey, String> {
+    if let Ok(key) = p256::SecretKey::from_sec1_der(der) {
+        return Ok(PrivateKey::P256(key));
+    }
+    p384::SecretKey::from_sec1_der(der)
+        .map(PrivateKey::P384)
+        .map_err(|_| "Unsupported EC private key: expected P-256 or P-384".to_string())
+}
+
+fn pkcs1_public(der: &[u8]) -> Result<PublicKey, String> {
+    RsaPublicKey::from_pkcs1_der(der)
+        .map(PublicKey::Rsa)
+        .map_err(|e| format!("Failed to parse RSA public key: {}", e))
+}
+
+/// Extracts the subject public key from an X.509 certificate.
+fn certificate_public(der: &[u8]) -> Result<PublicKey, String> {
+    let certificate =
+        Certificate::from_der(der).map_err(|e| format!("Invalid X.509 certificate: {}", e))?;
+    let spki = certificate
+        .tbs_certificate
+        .subject_public_key_info
+        .to_der()
+        .map_err(|e| format!("Failed to read certificate public key: {}", e))?;
+    PublicKey::from_spki_der(&spki)
+}
+
+fn parse_pem_block(block: &str, passphrase: Option<&str>) -> Result<DetectedKey, String> {
+    if block.contains("Proc-Type: 4,ENCRYPTED") {
+        return Err("Legacy OpenSSL-encrypted PEM keys are not supported; \
+                    convert with `openssl pkcs8 -topk8`"
+            .to_string());
+    }
+    let (label, der) = pkcs8::der::pem::decode_vec(block.as_bytes())
+        .map_err(|e| format!("Invalid PEM: {}", e))?;
+    let private = |key: Result<PrivateKey, String>, format: &str| {
+        key.map(|key| DetectedKey {
+            key: Key::Private(key),
+            format: format.to_string(),
+        })
+    };
+    let public = |key: Result<PublicKey, String>, format: &str| {
+        key.map(|key| DetectedKey {
+            key: Key::Public(key),
+            format: format.to_string(),
+        })
+    };
+    match label {
+        "RSA PRIVATE KEY" => private(pkcs1_private(&der), "PKCS#1 private key"),
+        "PRIVATE KEY" => private(PrivateKey::from_pkcs8_der(&der), "PKCS#8 private key"),
+        "ENCRYPTED PRIVATE KEY" => private(
+            encrypted_pkcs8(&der, passphrase),
+            "Encrypted PKCS#8 private key",
+        ),
+        "EC PRIVATE KEY" => private(sec1_private(&der), "SEC1 EC private key"),
+        "RSA PUBLIC KEY" => public(pkcs1_public(&der), "PKCS#1 public key"),
+        "PUBLIC KEY" => public(PublicKey::from_spki_der(&der), "SPKI public key"),
+        "CERTIFICATE" => public(certificate_public(&der), "X.509 certificate"),
+        _ => Err(format!("Unsupported PEM type: {}", label)),
+    }
+}
+
+/// Tries every supported structure against raw DER, since it carries no
+/// label to say what it holds.
+fn parse_der(der: &[u8], passphrase: Option<&str>) -> Result<DetectedKey, String> {
+    let detected = |key: Key, format: &str| DetectedKey {
+        key,
+        format: format!("{} (DER)", format),
+    };
+    if let Ok(key) = PrivateKey::from_pkcs8_der(der) {
+        return Ok(detected(Key::Private(key), "PKCS#8 private key"));
+    }
+    if pkcs8::EncryptedPrivateKeyInfo::try_from(der).is_ok() {
+        let key = encrypted_pkcs8(der, passphrase)?;
+        return Ok(detected(Key::Private(key), "Encrypted PKCS#8 private key"));
+    }
+    if let Ok(key) = pkcs1_private(der) {
+        return Ok(detected(Key::Private(key), "PKCS#1 private key"));
+    }
+    if let Ok(key) = sec1_private(der) {
+        return Ok(detected(Key::Private(key), "SEC1 EC private key"));
+    }
+    if let Ok(key) = PublicKey::from_spki_der(der) {
+        return Ok(detected(Key::Public(key), "SPKI public key"));
+    }
+    if let Ok(key) = pkcs1_public(der) {
+        return Ok(detected(Key::Public(key), "PKCS#1 public key"));
+    }
+    if let Ok(key) = certificate_public(der) {
+        return Ok(detected(Key::Public(key), "X.509 certificate"));
+    }
+    Err("Unrecognised key: expected PKCS#1, PKCS#8, SEC1, SPKI or an X.509 certificate".to_string())
+}
+
+/// Detects and parses a key given as PEM, base64-encoded DER or a JWK.
+/// `passphrase` is only needed for encrypted PKCS#8 private keys.
+pub(crate) fn parse_key(input: &str, passphrase: Option<&str>) -> Result<DetectedKey, String> {
+    let input = input.trim();
+    if crate::jwk::is_json(input) {
+        return crate::jwk::parse_jwk_key(input);
+    }
+    if input.contains("-----BEGIN ") {
+        let block = first_pem_block(input).ok_or("Invalid PEM: missing END line")?;
+        return parse_pem_block(block, passphrase);
+    }
+    let base64: String = input.chars().filter(|c| !c.is_whitespace()).collect();
+    let der = STANDARD
+        .decode(base64)
+        .map_err(|_| "Unrecognised key: expected PEM, base64-encoded DER or a JWK".to_string())?;
+    parse_der(&der, passphrase)
+}
+
+fn check_kind(actual: KeyKind, expected: KeyKind) -> Result<(), String> {
+    if actual == expected {
+        Ok(())
+    } else {
+        Err(format!(
+            "Expected a {} key, got a {} key",
+            expected.name(),
+            actual.name()
+        ))
+    }
+}
+
+impl PrivateKey {
+    /// Tries each supported key type against a PKCS#8 `PrivateKeyInfo`;
+    /// each one checks the algorithm OID, so at most one matches.
+    fn from_pkcs8_der(der: &[u8]) -> Result<Self, String> {
+        if let Ok(key) = RsaPrivateKey::from_pkcs8_der(der) {
+            return Ok(PrivateKey::Rsa(key));
+        }
+        if let Ok(key) = p256::SecretKey::from_pkcs8_der(der) {
+            return Ok(PrivateKey::P256(key));
+        }
+        if let Ok(key) = p384::SecretKey::from_pkcs8_der(der) {
+            return Ok(PrivateKey::P384(key));
+        }
+        if let Ok(key) = ed25519_dalek::SigningKey::from_pkcs8_der(der) {
+            return Ok(PrivateKey::Ed25519(key));
+        }
+        Err("Unsupported PKCS#8 private key: expected RSA, P-256, P-384 or Ed25519".to_string())
+    }
+
+    /// Parses a private key in any format `parse_key` detects.
+    pub(crate) fn parse(input: &str, passphrase: Option<&str>) -> Result<Self, String> {
+        let detected = parse_key(input, passphrase)?;
+        match detected.key {
+            Key::Private(key) => Ok(key),
+            Key::Public(_) => Err(format!(
+                "Signing requires a private key, but this is a {}",
+                detected.format
+            )),
+        }
+    }
+
+    pub(crate) fn kind(&self) -> KeyKind {
+        match self {
+            PrivateKey::Rsa(_) => KeyKind::Rsa,
+            PrivateKey::P256(_) => KeyKind::P256,
+            PrivateKey::P384(_) => KeyKind::P384,
+            PrivateKey::Ed25519(_) => KeyKind::Ed25519,
+        }
+    }
+
+    pub(crate) fn public_key(&self) -> PublicKey {
+        match self {
+            PrivateKey::Rsa(key) => PublicKey::Rsa(RsaPublicKey::from(key)),
+            PrivateKey::P256(key) => PublicKey::P256(key.public_key()),
+            PrivateKey::P384(key) => PublicKey::P384(key.public_key()),
+            PrivateKey::Ed25519(key) => PublicKey::Ed25519(key.verifying_key()),
+        }
+    }
+
+    /// Encodes the key as PKCS#8 PEM, or PKCS#1 for RSA to match
+    /// `generate_rsa_keypair`.
+    pub(crate) fn to_pem(&self) -> Result<String, String> {
+        let pem = match self {
+            PrivateKey::Rsa(key) => key.to_pkcs1_pem(LineEnding::LF),
+            PrivateKey::P256(key) => key.to_pkcs8_pem(LineEnding::LF).map_err(Into::into),
+            PrivateKey::P384(key) => key.to_pkcs8_pem(LineEnding::LF).map_err(Into::into),
+            PrivateKey::Ed25519(key) => key.to_pkcs8_pem(LineEnding::LF).map_err(Into::into),
+        };
+        pem.map(|pem| pem.to_string())
+            .map_err(|e| format!("Failed to encode private key: {}", e))
+    }
+
+    /// Converts the key into the DER form `jsonwebtoken` signs with, checking
+    /// it suits `kind`.
+    pub(crate) fn to_encoding_key(&self, kind: KeyKind) -> Result<EncodingKey, String> {
+        check_kind(self.kind(), kind)?;
+        let encode_error =
+            |e: &dyn std::fmt::Display| format!("Failed to encode private key: {}", e);
+        Ok(match self {
+            PrivateKey::Rsa(key) => EncodingKey::from_rsa_der(
+                key.to_pkcs1_der().map_err(|e| encode_error(&e))?.as_bytes(),
+            ),
+            PrivateKey::P256(key) => EncodingKey::from_ec_der(
+                key.to_pkcs8_der().map_err(|e| encode_error(&e))?.as_bytes(),
+            ),
+            PrivateKey::P384(key) => EncodingKey::from_ec_der(
+                key.to_pkcs8_der().map_err(|e| encode_error(&e))?.as_bytes(),
+            ),
+            PrivateKey::Ed25519(key) => EncodingKey::from_ed_der(
+                key.to_pkcs8_der().map_err(|e| encode_error(&e))?.as_bytes(),
+            ),
+        })
+    }
+}
+
+impl PublicKey {
+    /// Parses a public key in any format `parse_key` detects, deriving it
+    /// from a private key so either half of a key pair can verify.
+    pub(crate) fn parse(input: &str, passphrase: Option<&str>) -> Result<Self, String> {
+        match parse_key(input, passphrase)?.key {
+            Key::Public(key) => Ok(key),
+            Key::Private(key) => Ok(key.public_key()),
+        }
+    }
+
+    fn from_spki_der(der: &[u8]) -> Result<Self, String> {
+        if let Ok(key) = RsaPublicKey::from_public_key_der(der) {
+            return Ok(PublicKey::Rsa(key));
+        }
+        if let Ok(key) = p256::PublicKey::from_public_key_der(der) {
+            return Ok(PublicKey::P256(key));
+        }
+        if let Ok(key) = p384::PublicKey::from_public_key_der(der) {
+            return Ok(PublicKey::P384(key));
+        }
+        if let Ok(key) = ed25519_dalek::VerifyingKey::from_public_key_der(der) {
+            return Ok(PublicKey::Ed25519(key));
+        }
+        Err("Unsupported public key: expected RSA, P-256, P-384 or Ed25519".to_string())
+    }
+
+    pub(crate) fn kind(&self) -> KeyKind {
+        match self {
+            PublicKey::Rsa(_) => KeyKind::Rsa,
+            PublicKey::P256(_) => KeyKind::P256,
+            PublicKey::P384(_) => KeyKind::P384,
+            PublicKey::Ed25519(_) => KeyKind::Ed25519,
+        }
+    }
+
+    /// Encodes the key as SPKI PEM, or PKCS#1 for RSA to match
+    /// `generate_rsa_keypair`.
+    pub(crate) fn to_pem(&self) -> Result<String, String> {
+        match self {
+            PublicKey::Rsa(key) => key.to_pkcs1_pem(LineEnding::LF).map_err(|e| e.to_string()),
+            PublicKey::P256(key) => key
+                .to_public_key_pem(LineEnding::LF)
+                .map_err(|e| e.to_string()),
+            PublicKey::P384(key) => key
+                .to_public_key_pem(LineEnding::LF)
+                .map_err(|e| e.to_string()),
+            PublicKey::Ed25519(key) => key
+                .to_public_key_pem(LineEnding::LF)
+                .map_err(|e| e.to_string()),
+        }
+        .map_err(|e| format!("Failed to encode public key: {}", e))
+    }
+
+    /// Converts the key into the raw form `jsonwebtoken` verifies with:
+    /// PKCS#1 DER for RSA, an uncompressed SEC1 point for ECDSA and the
+    /// 32-byte key for Ed25519.
+    pub(crate) fn to_decoding_key(&self, kind: KeyKind) -> Result<DecodingKey, String> {
+        check_kind(self.kind(), kind)?;
+        Ok(match self {
+            PublicKey::Rsa(key) => DecodingKey::from_rsa_der(
+                key.to_pkcs1_der()
+                    .map_err(|e| format!("Failed to encode public key: {}", e))?
+                    .as_bytes(),
+            ),
+            PublicKey::P256(key) => {
+                DecodingKey::from_ec_der(key.to_encoded_point(false).as_bytes())
+            }
+            PublicKey::P384(key) => {
+                DecodingKey::from_ec_der(key.to_encoded_point(false).as_bytes())
+            }
+            PublicKey::Ed25519(key) => DecodingKey::from_ed_der(key.as_bytes()),
+        })
+    }
+}
+
+/// Generates a new key pair of the given kind. `rsa_bits` is only used for RSA.
+pub(crate) fn generate_private_key(kind: KeyKind, rsa_bits: usize) -> Result<PrivateKey, String> {
+    use rand::rngs::OsRng;
+
+    Ok(match kind {
+        KeyKind::Rsa => PrivateKey::Rsa(
+            RsaPrivateKey::new(&mut OsRng, rsa_bits)
+                .map_err(|e| format!("Failed to generate private key: {}", e))?,
+        ),
+        KeyKind::P256 => PrivateKey::P256(p256::SecretKey::random(&mut OsRng)),
+        KeyKind::P384 => PrivateKey::P384(p384::SecretKey::random(&mut OsRng)),
+        KeyKind::Ed25519 => PrivateKey::Ed25519(ed25519_dalek::SigningKey::generate(&mut OsRng)),
+    })
+}
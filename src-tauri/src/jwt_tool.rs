@@ -1,17 +1,16 @@
+use crate::jwk::{self, Jwk};
+use crate::jwt_keys::{generate_private_key, parse_key, Key, KeyKind, PrivateKey, PublicKey};
+use crate::oidc_discovery::cached_issuer_keys;
 use chrono::{DateTime, Utc};
 use jsonwebtoken::{
     decode, decode_header, encode, Algorithm, DecodingKey, EncodingKey, Header,
     Validation,
 };
-use rsa::{
-    pkcs1::{DecodeRsaPrivateKey, DecodeRsaPublicKey, EncodeRsaPrivateKey, EncodeRsaPublicKey},
-    pkcs8::LineEnding,
-    RsaPrivateKey, RsaPublicKey,
-};
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value};
 use std::time::{SystemTime, UNIX_EPOCH};
 use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
+use tauri::AppHandle;
 
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -31,6 +30,43 @@ pub struct VerifyResult {
     error: Option<String>,
     decoded_header: Option<Map<String, Value>>,
     decoded_payload: Option<Map<String, Value>>,
+    matched_key: Option<MatchedKey>,
+    checks: Vec<ClaimCheck>,
+    failed_check: Option<String>, // the first failed check, if any
+}
+
+/// Claim validation for `verify_jwt`. Everything is optional: by default
+/// only `exp`, `nbf` and `iat` are checked, and only when present.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ValidationOptions {
+    issuers: Vec<String>, // accepted "iss" values
+    audiences: Vec<String>, // "aud" must contain at least one of these
+    subject: Option<String>,
+    required_claims: Vec<String>,
+    leeway: u64, // allowed clock skew in seconds
+    now: Option<i64>, // Unix seconds to validate at instead of the current time
+}
+
+/// One check performed by `verify_jwt`.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClaimCheck {
+    check: String, // "signature", "exp", "nbf", "iat", "iss", "aud", "sub" or "required:<claim>"
+    passed: bool,
+    message: String,
+}
+
+/// The JWKS entry that verified a token.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MatchedKey {
+    index: usize, // position in the JWKS "keys" array
+    kid: Option<String>,
+    alg: Option<String>,
+    key_type: String,
+    thumbprint: String, // RFC 7638
+    fetched_at: Option<i64>, // when an issuer's cached keys were fetched
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -38,17 +74,129 @@ pub struct VerifyResult {
 pub struct KeyPair {
     private_key: String,
     public_key: String,
+    private_jwk: Option<String>,
+    public_jwk: Option<String>,
+}
+
+/// What `inspect_key` detected about a pasted key or certificate.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KeyInfo {
+    format: String, // e.g. "PKCS#8 private key", "X.509 certificate"
+    key_type: String, // "RSA", "P-256", "P-384" or "Ed25519"
+    is_private: bool,
+    public_key: String, // PEM, extracted or derived from the input
+    private_key: Option<String>, // PEM, for private key input
+    public_jwk: String,
+    private_jwk: Option<String>,
+    thumbprint: String, // RFC 7638, also used as the JWK "kid"
+}
+
+impl VerifyResult {
+    /// A result for a token that failed `check` ("algorithm", "key" or
+    /// "signature") before its claims could be checked.
+    fn failed(check: &str, error: String) -> Self {
+        VerifyResult {
+            is_valid: false,
+            error: Some(error.clone()),
+            decoded_header: None,
+            decoded_payload: None,
+            matched_key: None,
+            checks: vec![ClaimCheck::new(check, false, error)],
+            failed_check: Some(check.to_string()),
+        }
+    }
+}
+
+impl ClaimCheck {
+    fn new(check: &str, passed: bool, message: String) -> Self {
+        ClaimCheck {
+            check: check.to_string(),
+            passed,
+            message,
+        }
+    }
+}
+
+fn parse_algorithm(algorithm: &str) -> Result<Algorithm, String> {
+    match algorithm {
+        "HS256" => Ok(Algorithm::HS256),
+        "HS384" => Ok(Algorithm::HS384),
+        "HS512" => Ok(Algorithm::HS512),
+        "RS256" => Ok(Algorithm::RS256),
+        "RS384" => Ok(Algorithm::RS384),
+        "RS512" => Ok(Algorithm::RS512),
+        "PS256" => Ok(Algorithm::PS256),
+        "PS384" => Ok(Algorithm::PS384),
+        "PS512" => Ok(Algorithm::PS512),
+        "ES256" => Ok(Algorithm::ES256),
+        "ES384" => Ok(Algorithm::ES384),
+        "EdDSA" => Ok(Algorithm::EdDSA),
+        _ => Err(format!("Unsupported algorithm: {}", algorithm)),
+    }
+}
+
+/// Builds the signing key for `alg`: the raw secret or an `oct` JWK for HMAC,
+/// otherwise a private key of the kind the algorithm needs, in any detected
+/// format.
+fn encoding_key(
+    alg: Algorithm,
+    secret: &str,
+    passphrase: Option<&str>,
+) -> Result<EncodingKey, String> {
+    match KeyKind::for_algorithm(alg) {
+        None if jwk::is_json(secret) => {
+            Ok(EncodingKey::from_secret(&jwk::parse_secret_jwk(secret)?))
+        }
+        None => Ok(EncodingKey::from_secret(secret.as_bytes())),
+        Some(kind) => PrivateKey::parse(secret, passphrase)?.to_encoding_key(kind),
+    }
+}
+
+/// Builds the verification key for `alg`. Asymmetric algorithms accept a
+/// public key, a certificate, or the private key it belongs to.
+fn decoding_key(
+    alg: Algorithm,
+    secret: &str,
+    passphrase: Option<&str>,
+) -> Result<DecodingKey, String> {
+    match KeyKind::for_algorithm(alg) {
+        None => Ok(DecodingKey::from_secret(secret.as_bytes())),
+        Some(kind) => PublicKey::parse(secret, passphrase)?.to_decoding_key(kind),
+    }
+}
+
+/// Serializes a JWK with its thumbprint as the `kid`, so it can go straight
+/// into a JWKS.
+fn jwk_json(mut jwk: Jwk, thumbprint: &str) -> Result<String, String> {
+    jwk.kid = Some(thumbprint.to_string());
+    jwk.to_json()
+}
+
+fn key_pair(private_key: PrivateKey, with_jwk: bool) -> Result<KeyPair, String> {
+    let public_key = private_key.public_key();
+    let (private_jwk, public_jwk) = if with_jwk {
+        let thumbprint = public_key.thumbprint();
+        (
+            Some(jwk_json(private_key.to_jwk()?, &thumbprint)?),
+            Some(jwk_json(public_key.to_jwk(), &thumbprint)?),
+        )
+    } else {
+        (None, None)
+    };
+    Ok(KeyPair {
+        private_key: private_key.to_pem()?,
+        public_key: public_key.to_pem()?,
+        private_jwk,
+        public_jwk,
+    })
 }
 
 #[tauri::command]
 pub fn decode_jwt(token: &str) -> Result<JwtParts, String> {
     // Remove Bearer prefix if present
     let token = token.trim();
-    let token = if token.starts_with("Bearer ") {
-        &token[7..]
-    } else {
-        token
-    };
+    let token = token.strip_prefix("Bearer ").unwrap_or(token);
 
     // Split token into parts
     let parts: Vec<&str> = token.split('.').collect();
@@ -101,176 +249,354 @@ pub fn encode_jwt(
     payload: Map<String, Value>,
     secret: &str,
     algorithm: &str,
+    passphrase: Option<String>,
 ) -> Result<String, String> {
-    let alg = match algorithm {
-        "HS256" => Algorithm::HS256,
-        "HS384" => Algorithm::HS384,
-        "HS512" => Algorithm::HS512,
-        "RS256" => Algorithm::RS256,
-        "RS384" => Algorithm::RS384,
-        "RS512" => Algorithm::RS512,
-        _ => return Err(format!("Unsupported algorithm: {}", algorithm)),
+    let alg = parse_algorithm(algorithm)?;
+    let header = Header::new(alg);
+    let key = encoding_key(alg, secret, passphrase.as_deref())?;
+
+    encode(&header, &payload, &key).map_err(|e| format!("Failed to encode JWT: {}", e))
+}
+
+/// The keys to try for a token: the secret or key itself, or the JWKS
+/// candidates matching the token's `kid` and `alg`.
+fn resolve_keys(
+    app: &AppHandle,
+    token: &str,
+    secret: &str,
+    alg: Algorithm,
+    passphrase: Option<&str>,
+    jwks_issuer: Option<&str>,
+) -> Result<Vec<(DecodingKey, Option<MatchedKey>)>, String> {
+    let kind = match KeyKind::for_algorithm(alg) {
+        Some(kind) if jwks_issuer.is_some() || jwk::is_json(secret) => kind,
+        None if jwks_issuer.is_some() => {
+            return Err(format!("Issuer keys can't verify {:?} tokens", alg));
+        }
+        None if jwk::is_json(secret) => return secret_keys(token, secret, alg),
+        _ => return Ok(vec![(decoding_key(alg, secret, passphrase)?, None)]),
     };
 
-    let header = Header::new(alg);
-    
-    let key = match algorithm {
-        "HS256" | "HS384" | "HS512" => EncodingKey::from_secret(secret.as_bytes()),
-        "RS256" | "RS384" | "RS512" => {
-            let private_key = RsaPrivateKey::from_pkcs1_pem(secret)
-                .map_err(|e| format!("Failed to parse RSA private key: {}", e))?;
-            let pem = private_key
-                .to_pkcs1_pem(LineEnding::LF)
-                .map_err(|e| format!("Failed to encode private key: {}", e))?;
-            EncodingKey::from_rsa_pem(pem.as_bytes())
-                .map_err(|e| format!("Failed to create encoding key: {}", e))?
+    let (jwks, fetched_at) = match jwks_issuer {
+        Some(issuer) => {
+            let (jwks, fetched_at) = cached_issuer_keys(app, issuer)?;
+            (jwks, Some(fetched_at))
         }
-        _ => return Err(format!("Unsupported algorithm: {}", algorithm)),
+        None => (jwk::parse_jwks(secret)?, None),
     };
+    let kid = token_kid(token)?;
+    jwk::select_keys(&jwks, kid.as_deref(), alg)?
+        .into_iter()
+        .map(|candidate| {
+            let matched = MatchedKey {
+                index: candidate.index,
+                kid: candidate.jwk.kid,
+                alg: candidate.jwk.alg,
+                key_type: candidate.key.kind().name().to_string(),
+                thumbprint: candidate.key.thumbprint(),
+                fetched_at,
+            };
+            Ok((candidate.key.to_decoding_key(kind)?, Some(matched)))
+        })
+        .collect()
+}
 
-    encode(&header, &payload, &key).map_err(|e| format!("Failed to encode JWT: {}", e))
+/// The `oct` keys of a pasted JWK or JWKS that match an HMAC token.
+fn secret_keys(
+    token: &str,
+    secret: &str,
+    alg: Algorithm,
+) -> Result<Vec<(DecodingKey, Option<MatchedKey>)>, String> {
+    let jwks = jwk::parse_jwks(secret)?;
+    let kid = token_kid(token)?;
+    let candidates = jwk::select_secrets(&jwks, kid.as_deref(), alg)?;
+    Ok(candidates
+        .into_iter()
+        .map(|candidate| {
+            let matched = MatchedKey {
+                index: candidate.index,
+                thumbprint: candidate.jwk.secret_thumbprint(),
+                kid: candidate.jwk.kid,
+                alg: candidate.jwk.alg,
+                key_type: "oct".to_string(),
+                fetched_at: None,
+            };
+            (DecodingKey::from_secret(&candidate.secret), Some(matched))
+        })
+        .collect())
 }
 
+fn token_kid(token: &str) -> Result<Option<String>, String> {
+    Ok(decode_header(token)
+        .map_err(|e| format!("Invalid token header: {}", e))?
+        .kid)
+}
+
+/// Verifies `token` with a secret, a key or certificate, or a JWK/JWKS.
+/// With a JWKS the key is chosen by the token's `kid` and `alg` and
+/// reported in `matched_key`. `jwks_issuer` verifies against the keys
+/// cached by `fetch_issuer_jwks` instead of `secret`. Claims are then
+/// validated against `options`, and every check is listed in `checks`;
+/// an unusable algorithm or key is reported as a failed check too.
 #[tauri::command]
-pub fn verify_jwt(token: &str, secret: &str, algorithm: &str) -> Result<VerifyResult, String> {
+pub fn verify_jwt(
+    app: AppHandle,
+    token: &str,
+    secret: &str,
+    algorithm: &str,
+    passphrase: Option<String>,
+    jwks_issuer: Option<String>,
+    options: Option<ValidationOptions>,
+) -> VerifyResult {
     let token = token.trim();
-    let token = if token.starts_with("Bearer ") {
-        &token[7..]
-    } else {
-        token
-    };
+    let token = token.strip_prefix("Bearer ").unwrap_or(token);
 
-    let alg = match algorithm {
-        "HS256" => Algorithm::HS256,
-        "HS384" => Algorithm::HS384,
-        "HS512" => Algorithm::HS512,
-        "RS256" => Algorithm::RS256,
-        "RS384" => Algorithm::RS384,
-        "RS512" => Algorithm::RS512,
-        _ => {
-            return Ok(VerifyResult {
-                is_valid: false,
-                error: Some(format!("Unsupported algorithm: {}", algorithm)),
-                decoded_header: None,
-                decoded_payload: None,
-            })
-        }
+    let alg = match parse_algorithm(algorithm) {
+        Ok(alg) => alg,
+        Err(e) => return VerifyResult::failed("algorithm", e),
     };
 
+    // jsonwebtoken only checks the signature; claims are checked by
+    // `check_claims` so every check can be reported
     let mut validation = Validation::new(alg);
-    validation.validate_exp = false; // We'll check expiry separately for better error messages
-
-    let key = match algorithm {
-        "HS256" | "HS384" | "HS512" => DecodingKey::from_secret(secret.as_bytes()),
-        "RS256" | "RS384" | "RS512" => {
-            // Try parsing as public key first
-            match RsaPublicKey::from_pkcs1_pem(secret) {
-                Ok(public_key) => {
-                    let pem = public_key
-                        .to_pkcs1_pem(LineEnding::LF)
-                        .map_err(|e| format!("Failed to encode public key: {}", e))?;
-                    DecodingKey::from_rsa_pem(pem.as_bytes())
-                        .map_err(|e| format!("Failed to create decoding key: {}", e))?
-                }
-                Err(_) => {
-                    // Try parsing as private key and extract public key
-                    let private_key = RsaPrivateKey::from_pkcs1_pem(secret).map_err(|e| {
-                        format!("Failed to parse RSA key: {}", e)
-                    })?;
-                    let public_key = RsaPublicKey::from(&private_key);
-                    let pem = public_key
-                        .to_pkcs1_pem(LineEnding::LF)
-                        .map_err(|e| format!("Failed to encode public key: {}", e))?;
-                    DecodingKey::from_rsa_pem(pem.as_bytes())
-                        .map_err(|e| format!("Failed to create decoding key: {}", e))?
-                }
-            }
-        }
-        _ => {
-            return Ok(VerifyResult {
-                is_valid: false,
-                error: Some(format!("Unsupported algorithm: {}", algorithm)),
-                decoded_header: None,
-                decoded_payload: None,
-            })
-        }
+    validation.validate_exp = false;
+    validation.validate_aud = false;
+    validation.required_spec_claims.clear();
+    let options = options.unwrap_or_default();
+
+    let jwks_issuer = jwks_issuer.filter(|issuer| !issuer.trim().is_empty());
+    let keys = resolve_keys(
+        &app,
+        token,
+        secret,
+        alg,
+        passphrase.as_deref(),
+        jwks_issuer.as_deref(),
+    );
+    let keys = match keys {
+        Ok(keys) => keys,
+        Err(e) => return VerifyResult::failed("key", e),
     };
 
-    match decode::<Map<String, Value>>(token, &key, &validation) {
-        Ok(token_data) => {
-            // Check expiration manually
-            let now = SystemTime::now()
-                .duration_since(UNIX_EPOCH)
-                .unwrap()
-                .as_secs() as i64;
-            
-            if let Some(exp) = token_data.claims.get("exp").and_then(|v| v.as_i64()) {
-                if now > exp {
-                    return Ok(VerifyResult {
-                        is_valid: false,
-                        error: Some(format!(
-                            "Token expired at {}",
-                            DateTime::<Utc>::from_timestamp(exp, 0)
-                                .map(|dt| dt.to_rfc3339())
-                                .unwrap_or_else(|| exp.to_string())
-                        )),
-                        decoded_header: Some(
-                            serde_json::to_value(&token_data.header)
-                                .ok()
-                                .and_then(|v| v.as_object().cloned())
-                                .unwrap_or_default(),
-                        ),
-                        decoded_payload: Some(token_data.claims),
-                    });
-                }
+    // Without a kid every suitable JWKS key is tried in turn
+    let mut last_error = String::new();
+    for (key, matched_key) in keys {
+        match decode::<Map<String, Value>>(token, &key, &validation) {
+            Ok(token_data) => return verified(token_data, matched_key, algorithm, &options),
+            Err(e) => last_error = e.to_string(),
+        }
+    }
+    VerifyResult::failed("signature", format!("Verification failed: {}", last_error))
+}
+
+fn verified(
+    token_data: jsonwebtoken::TokenData<Map<String, Value>>,
+    matched_key: Option<MatchedKey>,
+    algorithm: &str,
+    options: &ValidationOptions,
+) -> VerifyResult {
+    let header_map = serde_json::to_value(&token_data.header)
+        .ok()
+        .and_then(|v| v.as_object().cloned());
+
+    let mut checks = vec![ClaimCheck::new(
+        "signature",
+        true,
+        format!("Valid {} signature", algorithm),
+    )];
+    checks.extend(check_claims(&token_data.claims, options));
+    let failed = checks.iter().find(|check| !check.passed);
+
+    VerifyResult {
+        is_valid: failed.is_none(),
+        error: failed.map(|check| check.message.clone()),
+        failed_check: failed.map(|check| check.check.clone()),
+        decoded_header: header_map,
+        decoded_payload: Some(token_data.claims),
+        matched_key,
+        checks,
+    }
+}
+
+fn format_time(timestamp: i64) -> String {
+    DateTime::<Utc>::from_timestamp(timestamp, 0)
+        .map(|dt| dt.to_rfc3339())
+        .unwrap_or_else(|| timestamp.to_string())
+}
+
+/// Reads a NumericDate claim, accepting the fractional seconds some issuers use.
+fn numeric_date(claims: &Map<String, Value>, name: &str) -> Option<Result<i64, String>> {
+    claims.get(name).map(|value| {
+        value
+            .as_i64()
+            .or_else(|| value.as_f64().map(|seconds| seconds as i64))
+            .ok_or_else(|| format!("\"{}\" is not a NumericDate", name))
+    })
+}
+
+/// Runs every applicable claim check. All checks run even after one fails,
+/// so the result shows everything that is wrong with the token.
+fn check_claims(claims: &Map<String, Value>, options: &ValidationOptions) -> Vec<ClaimCheck> {
+    let now = options.now.unwrap_or_else(|| {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs() as i64
+    });
+    // Claims come from the token, so none of the sums below may overflow
+    let leeway = i64::try_from(options.leeway).unwrap_or(i64::MAX);
+    let mut checks = Vec::new();
+
+    if let Some(exp) = numeric_date(claims, "exp") {
+        checks.push(match exp {
+            // RFC 7519 §4.1.4: the token must not be accepted on or after `exp`;
+            // a limit past the end of time never expires
+            Ok(exp) if exp.checked_add(leeway).is_some_and(|limit| now >= limit) => {
+                ClaimCheck::new("exp", false, format!("Token expired at {}", format_time(exp)))
             }
+            Ok(exp) => ClaimCheck::new("exp", true, format!("Expires at {}", format_time(exp))),
+            Err(e) => ClaimCheck::new("exp", false, e),
+        });
+    }
 
-            let header_map = serde_json::to_value(&token_data.header)
-                .ok()
-                .and_then(|v| v.as_object().cloned());
+    if let Some(nbf) = numeric_date(claims, "nbf") {
+        checks.push(match nbf {
+            Ok(nbf) if now.saturating_add(leeway) < nbf => ClaimCheck::new(
+                "nbf",
+                false,
+                format!("Token not valid before {}", format_time(nbf)),
+            ),
+            Ok(nbf) => ClaimCheck::new("nbf", true, format!("Valid since {}", format_time(nbf))),
+            Err(e) => ClaimCheck::new("nbf", false, e),
+        });
+    }
 
-            Ok(VerifyResult {
-                is_valid: true,
-                error: None,
-                decoded_header: header_map,
-                decoded_payload: Some(token_data.claims),
-            })
-        }
-        Err(e) => Ok(VerifyResult {
-            is_valid: false,
-            error: Some(format!("Verification failed: {}", e)),
-            decoded_header: None,
-            decoded_payload: None,
-        }),
+    if let Some(iat) = numeric_date(claims, "iat") {
+        checks.push(match iat {
+            Ok(iat) if iat > now.saturating_add(leeway) => ClaimCheck::new(
+                "iat",
+                false,
+                format!("Token issued in the future, at {}", format_time(iat)),
+            ),
+            Ok(iat) => ClaimCheck::new("iat", true, format!("Issued at {}", format_time(iat))),
+            Err(e) => ClaimCheck::new("iat", false, e),
+        });
+    }
+
+    if !options.issuers.is_empty() {
+        checks.push(match claims.get("iss").and_then(Value::as_str) {
+            Some(iss) if options.issuers.iter().any(|expected| expected == iss) => {
+                ClaimCheck::new("iss", true, format!("Issuer is \"{}\"", iss))
+            }
+            Some(iss) => ClaimCheck::new("iss", false, format!("Unexpected issuer \"{}\"", iss)),
+            None => ClaimCheck::new("iss", false, "Missing \"iss\" claim".to_string()),
+        });
+    }
+
+    if !options.audiences.is_empty() {
+        // "aud" may be a single string or an array of strings
+        let audiences: Vec<&str> = match claims.get("aud") {
+            Some(Value::String(aud)) => vec![aud.as_str()],
+            Some(Value::Array(auds)) => auds.iter().filter_map(Value::as_str).collect(),
+            _ => Vec::new(),
+        };
+        let matched = audiences
+            .iter()
+            .find(|aud| options.audiences.iter().any(|expected| expected == *aud));
+        checks.push(match matched {
+            Some(aud) => ClaimCheck::new("aud", true, format!("Audience includes \"{}\"", aud)),
+            None if audiences.is_empty() => {
+                ClaimCheck::new("aud", false, "Missing \"aud\" claim".to_string())
+            }
+            None => ClaimCheck::new(
+                "aud",
+                false,
+                format!("Audience {} is not accepted", audiences.join(", ")),
+            ),
+        });
+    }
+
+    if let Some(expected) = options.subject.as_deref().filter(|sub| !sub.is_empty()) {
+        checks.push(match claims.get("sub").and_then(Value::as_str) {
+            Some(sub) if sub == expected => {
+                ClaimCheck::new("sub", true, format!("Subject is \"{}\"", sub))
+            }
+            Some(sub) => ClaimCheck::new("sub", false, format!("Unexpected subject \"{}\"", sub)),
+            None => ClaimCheck::new("sub", false, "Missing \"sub\" claim".to_string()),
+        });
     }
+
+    for claim in options.required_claims.iter().map(|c| c.trim()).filter(|c| !c.is_empty()) {
+        let present = claims.get(claim).is_some_and(|value| !value.is_null());
+        checks.push(ClaimCheck::new(
+            &format!("required:{}", claim),
+            present,
+            if present {
+                format!("\"{}\" is present", claim)
+            } else {
+                format!("Missing required claim \"{}\"", claim)
+            },
+        ));
+    }
+
+    checks
 }
 
+/// Detects the format of a key or certificate (PEM, base64 DER or JWK) and
+/// converts it to PEM and JWK, with its RFC 7638 thumbprint.
 #[tauri::command]
-pub fn generate_rsa_keypair(key_size: usize) -> Result<KeyPair, String> {
-    use rand::rngs::OsRng;
+pub fn inspect_key(key: &str, passphrase: Option<String>) -> Result<KeyInfo, String> {
+    let detected = parse_key(key, passphrase.as_deref())?;
+    let (private_key, public_key) = match detected.key {
+        Key::Private(key) => {
+            let public_key = key.public_key();
+            (Some(key), public_key)
+        }
+        Key::Public(key) => (None, key),
+    };
+    let thumbprint = public_key.thumbprint();
+    Ok(KeyInfo {
+        format: detected.format,
+        key_type: public_key.kind().name().to_string(),
+        is_private: private_key.is_some(),
+        public_key: public_key.to_pem()?,
+        private_key: private_key.as_ref().map(PrivateKey::to_pem).transpose()?,
+        public_jwk: jwk_json(public_key.to_jwk(), &thumbprint)?,
+        private_jwk: private_key
+            .as_ref()
+            .map(|key| jwk_json(key.to_jwk()?, &thumbprint))
+            .transpose()?,
+        thumbprint,
+    })
+}
 
+/// Generates an RSA key pair as PKCS#1 PEM, plus JWKs when `jwk` is set.
+#[tauri::command]
+pub fn generate_rsa_keypair(key_size: usize, jwk: Option<bool>) -> Result<KeyPair, String> {
     let bits = match key_size {
         2048 | 3072 | 4096 => key_size,
         _ => return Err("Key size must be 2048, 3072, or 4096 bits".to_string()),
     };
 
-    let private_key = RsaPrivateKey::new(&mut OsRng, bits)
-        .map_err(|e| format!("Failed to generate private key: {}", e))?;
-    
-    let public_key = RsaPublicKey::from(&private_key);
+    key_pair(generate_private_key(KeyKind::Rsa, bits)?, jwk.unwrap_or(false))
+}
 
-    let private_pem = private_key
-        .to_pkcs1_pem(LineEnding::LF)
-        .map_err(|e| format!("Failed to encode private key: {}", e))?;
+/// Generates a P-256 (ES256) or P-384 (ES384) key pair as PKCS#8 and SPKI PEM.
+#[tauri::command]
+pub fn generate_ec_keypair(curve: &str, jwk: Option<bool>) -> Result<KeyPair, String> {
+    let kind = match curve {
+        "P-256" => KeyKind::P256,
+        "P-384" => KeyKind::P384,
+        _ => return Err(format!("Unsupported curve: {}", curve)),
+    };
 
-    let public_pem = public_key
-        .to_pkcs1_pem(LineEnding::LF)
-        .map_err(|e| format!("Failed to encode public key: {}", e))?;
+    key_pair(generate_private_key(kind, 0)?, jwk.unwrap_or(false))
+}
 
-    Ok(KeyPair {
-        private_key: private_pem.to_string(),
-        public_key: public_pem,
-    })
+/// Generates an Ed25519 (EdDSA) key pair as PKCS#8 and SPKI PEM.
+#[tauri::command]
+pub fn generate_ed25519_keypair(jwk: Option<bool>) -> Result<KeyPair, String> {
+    key_pair(generate_private_key(KeyKind::Ed25519, 0)?, jwk.unwrap_or(false))
 }
 
 #[tauri::command]
@@ -289,3 +615,92 @@ pub fn generate_jwt_secret(length: usize) -> Result<String, String> {
     
     Ok(secret)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn extreme_claims() -> Map<String, Value> {
+        let claims = json!({ "exp": i64::MAX, "nbf": i64::MAX, "iat": i64::MAX });
+        claims.as_object().cloned().unwrap()
+    }
+
+    fn check(checks: &[ClaimCheck], name: &str) -> bool {
+        checks.iter().find(|check| check.check == name).unwrap().passed
+    }
+
+    #[test]
+    fn extreme_numeric_dates_do_not_overflow() {
+        let options = ValidationOptions {
+            leeway: 60,
+            now: Some(1_700_000_000),
+            ..Default::default()
+        };
+        let checks = check_claims(&extreme_claims(), &options);
+        assert!(check(&checks, "exp"));
+        assert!(!check(&checks, "nbf"));
+        assert!(!check(&checks, "iat"));
+    }
+
+    #[test]
+    fn oversized_leeway_saturates() {
+        let options = ValidationOptions {
+            leeway: u64::MAX,
+            now: Some(i64::MAX),
+            ..Default::default()
+        };
+        let checks = check_claims(&extreme_claims(), &options);
+        assert!(checks.iter().all(|check| check.passed));
+
+        let claims = json!({ "exp": i64::MIN });
+        let checks = check_claims(claims.as_object().unwrap(), &options);
+        assert!(!check(&checks, "exp"));
+    }
+
+    #[test]
+    fn hmac_tokens_verify_against_oct_jwks() {
+        let mut header = Header::new(Algorithm::HS256);
+        header.kid = Some("b".to_string());
+        let claims = json!({ "sub": "user" });
+        let token = encode(&header, &claims, &EncodingKey::from_secret(b"secret-b")).unwrap();
+        let jwks = json!({ "keys": [
+            { "kty": "oct", "kid": "a", "k": URL_SAFE_NO_PAD.encode("secret-a") },
+            { "kty": "oct", "kid": "b", "k": URL_SAFE_NO_PAD.encode("secret-b") },
+        ] })
+        .to_string();
+
+        let keys = secret_keys(&token, &jwks, Algorithm::HS256).unwrap();
+        assert_eq!(keys.len(), 1);
+        let (key, matched) = &keys[0];
+        assert_eq!(matched.as_ref().unwrap().index, 1);
+        let validation = {
+            let mut validation = Validation::new(Algorithm::HS256);
+            validation.validate_exp = false;
+            validation.required_spec_claims.clear();
+            validation
+        };
+        assert!(decode::<Map<String, Value>>(&token, key, &validation).is_ok());
+
+        let rsa_only = json!({ "kty": "RSA", "n": "AQAB", "e": "AQAB" }).to_string();
+        assert!(secret_keys(&token, &rsa_only, Algorithm::HS256).is_err());
+    }
+
+    #[test]
+    fn expires_at_exactly_exp() {
+        let claims = json!({ "exp": 1_700_000_000 });
+        let claims = claims.as_object().unwrap();
+        let at = |now: i64, leeway: u64| {
+            let options = ValidationOptions {
+                leeway,
+                now: Some(now),
+                ..Default::default()
+            };
+            check(&check_claims(claims, &options), "exp")
+        };
+        assert!(at(1_699_999_999, 0));
+        assert!(!at(1_700_000_000, 0));
+        assert!(at(1_700_000_059, 60));
+        assert!(!at(1_700_000_060, 60));
+    }
+}
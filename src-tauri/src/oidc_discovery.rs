@@ -0,0 +1,202 @@
+use crate::jwk::{self, Jwk};
+use crate::jwt_keys::PublicKey;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Manager};
+use url::Url;
+
+/// An issuer's keys as stored in the on-disk cache.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CacheEntry {
+    issuer: String,
+    jwks_uri: String,
+    fetched_at: i64, // Unix seconds
+    jwks: Value,
+}
+
+/// One key of a fetched JWKS, for display.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JwksKeySummary {
+    kid: Option<String>,
+    kty: String,
+    crv: Option<String>,
+    alg: Option<String>,
+    key_use: Option<String>,
+    thumbprint: Option<String>, // RFC 7638, for key types this app can read
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssuerJwks {
+    issuer: String,
+    jwks_uri: String,
+    fetched_at: i64,
+    from_cache: bool,
+    keys: Vec<JwksKeySummary>,
+    jwks: String, // pretty-printed JWKS JSON
+}
+
+/// Issuers are compared without a trailing slash, as most IdPs are
+/// inconsistent about it between configuration and the `iss` claim.
+fn normalize_issuer(issuer: &str) -> Result<String, String> {
+    let issuer = issuer.trim().trim_end_matches('/');
+    let url = Url::parse(issuer).map_err(|e| format!("Invalid issuer URL: {}", e))?;
+    match url.scheme() {
+        "http" | "https" => Ok(issuer.to_string()),
+        scheme => Err(format!("Unsupported issuer URL scheme: {}", scheme)),
+    }
+}
+
+fn cache_path(app: &AppHandle, issuer: &str) -> Result<PathBuf, String> {
+    let dir = app
+        .path()
+        .app_cache_dir()
+        .map_err(|e| format!("Failed to locate cache directory: {}", e))?;
+    let name = hex::encode(Sha256::digest(issuer.as_bytes()));
+    Ok(dir.join("jwks").join(format!("{}.json", name)))
+}
+
+fn read_cache(app: &AppHandle, issuer: &str) -> Result<CacheEntry, String> {
+    let path = cache_path(app, issuer)?;
+    let content = fs::read_to_string(&path).map_err(|_| {
+        format!(
+            "No cached keys for {}; fetch them while online first",
+            issuer
+        )
+    })?;
+    serde_json::from_str(&content).map_err(|e| format!("Failed to read JWKS cache: {}", e))
+}
+
+fn write_cache(app: &AppHandle, entry: &CacheEntry) -> Result<(), String> {
+    let path = cache_path(app, &entry.issuer)?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| format!("Failed to create cache directory: {}", e))?;
+    }
+    let content = serde_json::to_string_pretty(entry)
+        .map_err(|e| format!("Failed to serialize JWKS cache: {}", e))?;
+    fs::write(&path, content).map_err(|e| format!("Failed to write JWKS cache: {}", e))
+}
+
+async fn get_json(client: &reqwest::Client, url: &str) -> Result<Value, String> {
+    let response = client
+        .get(url)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to fetch {}: {}", url, e))?;
+    let status = response.status();
+    if !status.is_success() {
+        return Err(format!("Failed to fetch {}: HTTP {}", url, status));
+    }
+    response
+        .json::<Value>()
+        .await
+        .map_err(|e| format!("Invalid JSON from {}: {}", url, e))
+}
+
+/// Fetches the discovery document, checks it belongs to `issuer`, then
+/// fetches the JWKS it points to.
+async fn fetch(issuer: &str) -> Result<CacheEntry, String> {
+    // reqwest is built without a bundled TLS provider; use ring like the updater
+    let _ = rustls::crypto::ring::default_provider().install_default();
+    let client = reqwest::Client::builder()
+        .timeout(Duration::from_secs(10))
+        .user_agent(concat!("DevWorkbench/", env!("CARGO_PKG_VERSION")))
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+
+    let discovery_url = format!("{}/.well-known/openid-configuration", issuer);
+    let discovery = get_json(&client, &discovery_url).await?;
+
+    let advertised = discovery
+        .get("issuer")
+        .and_then(Value::as_str)
+        .ok_or("Discovery document has no \"issuer\"")?;
+    if advertised.trim_end_matches('/') != issuer {
+        return Err(format!(
+            "Discovery document is for issuer \"{}\", not \"{}\"",
+            advertised, issuer
+        ));
+    }
+
+    let jwks_uri = discovery
+        .get("jwks_uri")
+        .and_then(Value::as_str)
+        .ok_or("Discovery document has no \"jwks_uri\"")?;
+    let jwks_uri = Url::parse(&discovery_url)
+        .and_then(|base| base.join(jwks_uri))
+        .map_err(|e| format!("Invalid jwks_uri: {}", e))?
+        .to_string();
+    let jwks = get_json(&client, &jwks_uri).await?;
+    jwk::jwks_from_value(jwks.clone())?;
+
+    let fetched_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as i64;
+
+    Ok(CacheEntry {
+        issuer: issuer.to_string(),
+        jwks_uri,
+        fetched_at,
+        jwks,
+    })
+}
+
+fn summarize(entry: CacheEntry, from_cache: bool) -> Result<IssuerJwks, String> {
+    let keys = jwk::jwks_from_value(entry.jwks.clone())?
+        .into_iter()
+        .map(|jwk| JwksKeySummary {
+            thumbprint: PublicKey::from_jwk(&jwk).ok().map(|key| key.thumbprint()),
+            kid: jwk.kid,
+            kty: jwk.kty,
+            crv: jwk.crv,
+            alg: jwk.alg,
+            key_use: jwk.key_use,
+        })
+        .collect();
+    let jwks = serde_json::to_string_pretty(&entry.jwks)
+        .map_err(|e| format!("Failed to serialize JWKS: {}", e))?;
+
+    Ok(IssuerJwks {
+        issuer: entry.issuer,
+        jwks_uri: entry.jwks_uri,
+        fetched_at: entry.fetched_at,
+        from_cache,
+        keys,
+        jwks,
+    })
+}
+
+/// Loads an issuer's cached keys for `verify_jwt`, with their fetch time.
+/// Never touches the network.
+pub(crate) fn cached_issuer_keys(app: &AppHandle, issuer: &str) -> Result<(Vec<Jwk>, i64), String> {
+    let issuer = normalize_issuer(issuer)?;
+    let entry = read_cache(app, &issuer)?;
+    Ok((jwk::jwks_from_value(entry.jwks)?, entry.fetched_at))
+}
+
+/// Fetches an issuer's signing keys through OpenID Connect discovery
+/// (`/.well-known/openid-configuration` → `jwks_uri`) and caches them on
+/// disk. With `offline` set, only the cache is read.
+#[tauri::command]
+pub async fn fetch_issuer_jwks(
+    app: AppHandle,
+    issuer: String,
+    offline: Option<bool>,
+) -> Result<IssuerJwks, String> {
+    let issuer = normalize_issuer(&issuer)?;
+
+    if offline.unwrap_or(false) {
+        return summarize(read_cache(&app, &issuer)?, true);
+    }
+
+    let entry = fetch(&issuer).await?;
+    write_cache(&app, &entry)?;
+    summarize(entry, false)
+}
@@ -0,0 +1,370 @@
+use argon2::password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString};
+use argon2::Argon2;
+use bcrypt::HashParts;
+use pbkdf2::Pbkdf2;
+use rand::RngCore;
+use scrypt::Scrypt;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// PBKDF2 iteration counts recommended by OWASP for each PRF.
+const PBKDF2_SHA256_ROUNDS: u32 = 600_000;
+const PBKDF2_SHA512_ROUNDS: u32 = 210_000;
+
+/// Length of the random salt generated for PHC-format hashes.
+const SALT_LEN: usize = 16;
+
+/// Upper bounds on cost parameters, checked before hashing so an oversized
+/// value is reported instead of exhausting memory or hanging the app.
+const MAX_ARGON2_MEMORY_KIB: u32 = 1024 * 1024; // 1 GiB
+const MAX_ARGON2_ITERATIONS: u32 = 64;
+const MAX_PBKDF2_ROUNDS: u32 = 10_000_000;
+const MAX_SCRYPT_LOG_N: u32 = 20;
+const MAX_SCRYPT_MEMORY: u64 = 1024 * 1024 * 1024; // 128 * r * N bytes
+const MAX_SCRYPT_PARALLELISM: u32 = 16;
+const MAX_BCRYPT_COST: u32 = 16;
+const MAX_OUTPUT_LEN: usize = 64; // longest hash a PHC string holds
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct PasswordHashOptions {
+    pub algorithm: String, // "bcrypt", "scrypt", "argon2id", "argon2i", "argon2d", "pbkdf2-sha256", "pbkdf2-sha512"
+    pub cost: Option<u32>, // bcrypt cost factor (4-31)
+    pub memory_kib: Option<u32>, // Argon2 memory in KiB
+    pub iterations: Option<u32>, // Argon2 passes or PBKDF2 rounds
+    pub parallelism: Option<u32>, // Argon2 lanes or scrypt p
+    pub log_n: Option<u8>, // scrypt CPU/memory cost as log2(N)
+    pub block_size: Option<u32>, // scrypt r
+    pub output_len: Option<usize>, // Derived key length in bytes
+}
+
+/// Parameters read back from an encoded password hash.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PasswordHashParams {
+    pub algorithm: String,
+    pub version: Option<String>,
+    pub cost: Option<u32>,
+    pub memory_kib: Option<u32>,
+    pub iterations: Option<u32>,
+    pub parallelism: Option<u32>,
+    pub log_n: Option<u32>,
+    pub block_size: Option<u32>,
+    pub output_len: Option<usize>,
+    pub salt: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PasswordHashResult {
+    hash: String,
+    params: PasswordHashParams,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PasswordVerifyResult {
+    is_valid: bool,
+    params: Option<PasswordHashParams>,
+    error: Option<String>,
+}
+
+fn is_bcrypt(hash: &str) -> bool {
+    ["$2a$", "$2b$", "$2x$", "$2y$"]
+        .iter()
+        .any(|prefix| hash.starts_with(prefix))
+}
+
+fn random_salt() -> Result<SaltString, String> {
+    let mut bytes = [0u8; SALT_LEN];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    SaltString::encode_b64(&bytes).map_err(|e| format!("Failed to encode salt: {}", e))
+}
+
+fn check_limit<T: PartialOrd + Display>(name: &str, value: T, max: T) -> Result<(), String> {
+    if value > max {
+        return Err(format!("{} is limited to {}, got {}", name, max, value));
+    }
+    Ok(())
+}
+
+fn check_argon2_limits(memory_kib: u32, iterations: u32) -> Result<(), String> {
+    check_limit("Argon2 memory (KiB)", memory_kib, MAX_ARGON2_MEMORY_KIB)?;
+    check_limit("Argon2 iterations", iterations, MAX_ARGON2_ITERATIONS)
+}
+
+fn check_pbkdf2_limits(rounds: u32) -> Result<(), String> {
+    check_limit("PBKDF2 rounds", rounds, MAX_PBKDF2_ROUNDS)
+}
+
+fn check_scrypt_limits(log_n: u32, block_size: u32, parallelism: u32) -> Result<(), String> {
+    check_limit("scrypt log_n", log_n, MAX_SCRYPT_LOG_N)?;
+    check_limit("scrypt parallelism", parallelism, MAX_SCRYPT_PARALLELISM)?;
+    let memory = (128 * u64::from(block_size)) << log_n;
+    check_limit(
+        "scrypt memory (128 * r * N bytes)",
+        memory,
+        MAX_SCRYPT_MEMORY,
+    )
+}
+
+/// Applies the cost limits to the parameters of an existing hash.
+fn check_params_limits(params: &PasswordHashParams) -> Result<(), String> {
+    if let Some(cost) = params.cost {
+        check_limit("bcrypt cost", cost, MAX_BCRYPT_COST)?;
+    }
+    if params.algorithm.starts_with("argon2") {
+        check_argon2_limits(
+            params.memory_kib.unwrap_or(argon2::Params::DEFAULT_M_COST),
+            params.iterations.unwrap_or(argon2::Params::DEFAULT_T_COST),
+        )?;
+    } else if params.algorithm.starts_with("pbkdf2") {
+        if let Some(rounds) = params.iterations {
+            check_pbkdf2_limits(rounds)?;
+        }
+    }
+    if let Some(log_n) = params.log_n {
+        check_scrypt_limits(
+            log_n,
+            params.block_size.unwrap_or(scrypt::Params::RECOMMENDED_R),
+            params.parallelism.unwrap_or(scrypt::Params::RECOMMENDED_P),
+        )?;
+    }
+    Ok(())
+}
+
+fn argon2_hasher(
+    algorithm: argon2::Algorithm,
+    options: &PasswordHashOptions,
+) -> Result<Argon2<'static>, String> {
+    let memory_kib = options.memory_kib.unwrap_or(argon2::Params::DEFAULT_M_COST);
+    let iterations = options.iterations.unwrap_or(argon2::Params::DEFAULT_T_COST);
+    check_argon2_limits(memory_kib, iterations)?;
+    let params = argon2::Params::new(
+        memory_kib,
+        iterations,
+        options
+            .parallelism
+            .unwrap_or(argon2::Params::DEFAULT_P_COST),
+        Some(
+            options
+                .output_len
+                .unwrap_or(argon2::Params::DEFAULT_OUTPUT_LEN),
+        ),
+    )
+    .map_err(|e| format!("Invalid Argon2 parameters: {}", e))?;
+    Ok(Argon2::new(algorithm, argon2::Version::V0x13, params))
+}
+
+fn compute_password_hash(password: &str, options: &PasswordHashOptions) -> Result<String, String> {
+    let algorithm = options.algorithm.trim().to_lowercase();
+    if algorithm == "bcrypt" {
+        let cost = options.cost.unwrap_or(bcrypt::DEFAULT_COST);
+        check_limit("bcrypt cost", cost, MAX_BCRYPT_COST)?;
+        return bcrypt::hash(password, cost).map_err(|e| format!("Failed to hash password: {}", e));
+    }
+
+    if let Some(output_len) = options.output_len {
+        check_limit("Output length (bytes)", output_len, MAX_OUTPUT_LEN)?;
+    }
+
+    let salt = random_salt()?;
+    let password = password.as_bytes();
+    let hash = match algorithm.as_str() {
+        "argon2id" => {
+            argon2_hasher(argon2::Algorithm::Argon2id, options)?.hash_password(password, &salt)
+        }
+        "argon2i" => {
+            argon2_hasher(argon2::Algorithm::Argon2i, options)?.hash_password(password, &salt)
+        }
+        "argon2d" => {
+            argon2_hasher(argon2::Algorithm::Argon2d, options)?.hash_password(password, &salt)
+        }
+        "scrypt" => {
+            let log_n = options.log_n.unwrap_or(scrypt::Params::RECOMMENDED_LOG_N);
+            let block_size = options.block_size.unwrap_or(scrypt::Params::RECOMMENDED_R);
+            let parallelism = options.parallelism.unwrap_or(scrypt::Params::RECOMMENDED_P);
+            check_scrypt_limits(u32::from(log_n), block_size, parallelism)?;
+            let params = scrypt::Params::new(
+                log_n,
+                block_size,
+                parallelism,
+                options
+                    .output_len
+                    .unwrap_or(scrypt::Params::RECOMMENDED_LEN),
+            )
+            .map_err(|e| format!("Invalid scrypt parameters: {}", e))?;
+            Scrypt.hash_password_customized(password, None, None, params, &salt)
+        }
+        "pbkdf2-sha256" | "pbkdf2-sha512" => {
+            let (prf, rounds, output_length) = if algorithm == "pbkdf2-sha256" {
+                (pbkdf2::Algorithm::Pbkdf2Sha256, PBKDF2_SHA256_ROUNDS, 32)
+            } else {
+                (pbkdf2::Algorithm::Pbkdf2Sha512, PBKDF2_SHA512_ROUNDS, 64)
+            };
+            let rounds = options.iterations.unwrap_or(rounds);
+            check_pbkdf2_limits(rounds)?;
+            let params = pbkdf2::Params {
+                rounds,
+                output_length: options.output_len.unwrap_or(output_length),
+            };
+            Pbkdf2.hash_password_customized(password, Some(prf.ident()), None, params, &salt)
+        }
+        _ => {
+            return Err(format!(
+                "Unsupported password hash algorithm: {}",
+                algorithm
+            ))
+        }
+    };
+
+    hash.map(|hash| hash.to_string())
+        .map_err(|e| format!("Failed to hash password: {}", e))
+}
+
+fn parse_bcrypt(hash: &str) -> Result<PasswordHashParams, String> {
+    let parts = HashParts::from_str(hash).map_err(|e| format!("Invalid bcrypt hash: {}", e))?;
+    Ok(PasswordHashParams {
+        algorithm: "bcrypt".to_string(),
+        version: hash.get(1..3).map(str::to_string),
+        cost: Some(parts.get_cost()),
+        salt: Some(parts.get_salt()),
+        output_len: Some(23),
+        ..Default::default()
+    })
+}
+
+fn parse_phc(hash: &PasswordHash) -> PasswordHashParams {
+    let decimal = |name: &str| hash.params.get_decimal(name);
+    let algorithm = hash.algorithm.as_str().to_string();
+    let mut params = PasswordHashParams {
+        version: hash.version.map(|v| v.to_string()),
+        salt: hash.salt.map(|salt| salt.as_str().to_string()),
+        output_len: hash.hash.map(|output| output.len()),
+        ..Default::default()
+    };
+
+    if algorithm.starts_with("argon2") {
+        params.memory_kib = decimal("m");
+        params.iterations = decimal("t");
+        params.parallelism = decimal("p");
+    } else if algorithm == "scrypt" {
+        params.log_n = decimal("ln");
+        params.block_size = decimal("r");
+        params.parallelism = decimal("p");
+    } else if algorithm.starts_with("pbkdf2") {
+        params.iterations = decimal("i");
+    }
+    params.algorithm = algorithm;
+    params
+}
+
+/// Parses `hash` and checks `password` against it, reporting its parameters.
+fn check_password(password: &str, hash: &str) -> Result<(bool, PasswordHashParams), String> {
+    if is_bcrypt(hash) {
+        let params = parse_bcrypt(hash)?;
+        check_params_limits(&params)?;
+        let matched =
+            bcrypt::verify(password, hash).map_err(|e| format!("Invalid bcrypt hash: {}", e))?;
+        return Ok((matched, params));
+    }
+
+    let parsed = PasswordHash::new(hash).map_err(|e| format!("Invalid PHC string: {}", e))?;
+    let params = parse_phc(&parsed);
+    check_params_limits(&params)?;
+    let verifiers: [&dyn PasswordVerifier; 3] = [&Argon2::default(), &Scrypt, &Pbkdf2];
+    match parsed.verify_password(&verifiers, password) {
+        Ok(()) => Ok((true, params)),
+        Err(argon2::password_hash::Error::Password) => Ok((false, params)),
+        Err(argon2::password_hash::Error::Algorithm) => Err(format!(
+            "Unsupported password hash algorithm: {}",
+            params.algorithm
+        )),
+        Err(e) => Err(format!("Failed to verify password: {}", e)),
+    }
+}
+
+/// Hashes a password with bcrypt, scrypt, Argon2 or PBKDF2 and a random salt.
+///
+/// Unset cost parameters fall back to each algorithm's recommended defaults.
+/// Costs are capped (Argon2 memory at 1 GiB and 64 iterations, scrypt log_n at
+/// 20 and 1 GiB of memory, bcrypt cost at 16, PBKDF2 at 10 million rounds) and
+/// larger values are rejected.
+#[tauri::command]
+pub async fn hash_password(
+    password: String,
+    options: PasswordHashOptions,
+) -> Result<PasswordHashResult, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let hash = compute_password_hash(&password, &options)?;
+        let params = if is_bcrypt(&hash) {
+            parse_bcrypt(&hash)?
+        } else {
+            let parsed =
+                PasswordHash::new(&hash).map_err(|e| format!("Invalid PHC string: {}", e))?;
+            parse_phc(&parsed)
+        };
+        Ok(PasswordHashResult { hash, params })
+    })
+    .await
+    .map_err(|e| format!("Password hashing failed: {}", e))?
+}
+
+/// Verifies a password against a PHC string (`$argon2id$...`, `$scrypt$...`,
+/// `$pbkdf2-sha256$...`) or a bcrypt `$2b$` hash.
+#[tauri::command]
+pub async fn verify_password(
+    password: String,
+    hash: String,
+) -> Result<PasswordVerifyResult, String> {
+    tauri::async_runtime::spawn_blocking(move || match check_password(&password, hash.trim()) {
+        Ok((is_valid, params)) => PasswordVerifyResult {
+            is_valid,
+            params: Some(params),
+            error: if is_valid {
+                None
+            } else {
+                Some("Password does not match".to_string())
+            },
+        },
+        Err(e) => PasswordVerifyResult {
+            is_valid: false,
+            params: None,
+            error: Some(e),
+        },
+    })
+    .await
+    .map_err(|e| format!("Password verification failed: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SALT: &str = "c2FsdHNhbHRzYWx0c2FsdA";
+    const HASH: &str = "aGFzaGhhc2hoYXNoaGFzaGhhc2hoYXNoaGFzaGhhc2g";
+
+    #[test]
+    fn rejects_oversized_iterations_in_phc_strings() {
+        let argon2 = format!("$argon2id$v=19$m=19456,t=4294967295,p=1${}${}", SALT, HASH);
+        let err = check_password("password", &argon2).unwrap_err();
+        assert!(err.contains("Argon2 iterations"), "{}", err);
+
+        let pbkdf2 = format!("$pbkdf2-sha256$i=4294967295,l=32${}${}", SALT, HASH);
+        let err = check_password("password", &pbkdf2).unwrap_err();
+        assert!(err.contains("PBKDF2 rounds"), "{}", err);
+    }
+
+    #[test]
+    fn rejects_oversized_iterations_in_options() {
+        for algorithm in ["argon2id", "pbkdf2-sha256"] {
+            let options = PasswordHashOptions {
+                algorithm: algorithm.to_string(),
+                iterations: Some(u32::MAX),
+                ..Default::default()
+            };
+            assert!(compute_password_hash("password", &options).is_err());
+        }
+    }
+}
@@ -1,5 +1,17 @@
+use crate::id_generator::{decode_crockford, encode_crockford, id_rng, monotonic_v7s, IdClock};
+use chrono::{DateTime, SecondsFormat};
+use data_encoding::{BASE32, BASE64, BASE64URL_NOPAD, BASE64_NOPAD};
+use rand::Rng;
 use serde::{Deserialize, Serialize};
-use uuid::{Uuid, Version};
+use std::collections::HashMap;
+use std::time::{SystemTime, UNIX_EPOCH};
+use uuid::{Builder, ContextV7, Timestamp, Uuid, Version};
+
+/// 100ns ticks between the Gregorian epoch (1582-10-15) and the Unix epoch.
+const GREGORIAN_UNIX_OFFSET: u64 = 0x01B2_1DD2_1381_4000;
+
+/// Bits left for custom fields in a v8 UUID once the version and variant are set.
+const V8_PAYLOAD_BITS: u32 = 122;
 
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -10,6 +22,45 @@ pub struct ParsedUuid {
     pub version: String,
     pub variant: String,
     pub error_message: Option<String>,
+    pub timestamp: Option<String>, // RFC 3339, for v1, v6 and v7
+    pub unix_millis: Option<i64>,
+    pub clock_sequence: Option<u16>, // v1 and v6
+    pub node_id: Option<String>,     // v1 and v6
+    pub random_bits: Option<String>, // Hex of the random payload of v4 and v7
+    pub fields: Vec<UuidField>,
+    pub custom_fields: Vec<CustomField>, // v8 fields decoded with the given template
+}
+
+/// One field of the 128-bit layout, counted from the most significant bit.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UuidField {
+    pub name: String,
+    pub bit_offset: u32,
+    pub bit_width: u32,
+    pub bits: String,
+    pub hex: String,
+}
+
+/// One field of a v8 layout template. Fields fill the 122 payload bits from
+/// the most significant end, skipping over the version and variant bits.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct V8Field {
+    pub name: String,
+    pub width: u32,
+    pub source: String, // "timestamp" (Unix ms), "counter", "constant", "random"
+    pub value: Option<String>, // Constant value or counter start, decimal or 0x-prefixed hex
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomField {
+    pub name: String,
+    pub source: String,
+    pub value: String,
+    pub hex: String,
+    pub timestamp: Option<String>, // RFC 3339, for timestamp fields
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -17,10 +68,331 @@ pub struct ParsedUuid {
 pub struct GenerateOptions {
     pub format: String, // "standard", "no-hyphens", "uppercase", "uppercase-no-hyphens"
     pub count: usize,
+    pub namespace: Option<String>, // v3/v5: "dns", "url", "oid", "x500" or a custom UUID
+    #[serde(default)]
+    pub names: Vec<String>, // v3/v5: one UUID is derived from each name
+    pub node_id: Option<String>, // v1/v6: MAC address; a random multicast node when omitted
+    pub clock_sequence: Option<u16>, // v1/v6: 14-bit clock sequence; random when omitted
+    pub template: Option<Vec<V8Field>>, // v8: custom bit layout
+    pub seed: Option<u64>, // Reproducible output from a ChaCha20 PRNG
+    pub timestamp: Option<i64>, // Fixed clock in Unix ms; seeded runs default to 2024-01-01
+}
+
+/// Every representation `convert_uuid` produces for a single UUID.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UuidConversions {
+    pub input_format: String, // The representation the input was recognised as
+    pub hyphenated: String,
+    pub simple: String,
+    pub braced: String,
+    pub urn: String,
+    pub base64: String,
+    pub base64_url: String, // Unpadded, URL-safe alphabet
+    pub base32: String, // RFC 4648, padded
+    pub crockford: String, // Crockford base32, as used by ULID
+    pub decimal: String,
+    pub bytes: String, // Big-endian hex byte array
+    pub java_bytes: String, // Big-endian signed bytes, as in a Java byte[]
+    pub guid_bytes: String, // Mixed-endian order of .NET Guid.ToByteArray()
+    pub mysql_swap_hex: String, // UUID_TO_BIN(uuid, 1) as hex
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InvalidUuidLine {
+    pub line: usize, // 1-based
+    pub text: String,
+    pub error: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DuplicateUuid {
+    pub uuid: String,
+    pub lines: Vec<usize>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UuidVersionCount {
+    pub version: String,
+    pub count: usize,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimestampedUuid {
+    pub line: usize,
+    pub uuid: String,
+    pub timestamp: Option<String>,
 }
 
+/// Summary of a pasted list of UUIDs, one per line.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UuidListAnalysis {
+    pub total_lines: usize, // Non-blank lines
+    pub valid_count: usize,
+    pub unique_count: usize,
+    pub invalid_lines: Vec<InvalidUuidLine>,
+    pub duplicates: Vec<DuplicateUuid>,
+    pub versions: Vec<UuidVersionCount>,
+    pub earliest: Option<TimestampedUuid>, // Time-based (v1/v6/v7) UUIDs only
+    pub latest: Option<TimestampedUuid>,
+    pub is_sorted: Option<bool>, // Whether time-based UUIDs appear in timestamp order
+    pub first_unsorted_line: Option<usize>,
+}
+
+fn format_uuid(uuid: &Uuid, format: &str) -> String {
+    match format {
+        "standard" => uuid.hyphenated().to_string(),
+        "no-hyphens" => uuid.simple().to_string(),
+        "uppercase" => uuid.hyphenated().to_string().to_uppercase(),
+        "uppercase-no-hyphens" => uuid.simple().to_string().to_uppercase(),
+        _ => uuid.hyphenated().to_string(),
+    }
+}
+
+/// Parses a MAC address written with `:`, `-` or no separators.
+fn parse_node_id(node_id: &str) -> Result<[u8; 6], String> {
+    let digits: String = node_id
+        .trim()
+        .chars()
+        .filter(|c| !matches!(c, ':' | '-' | '.'))
+        .collect();
+    let bytes = hex::decode(&digits).map_err(|e| format!("Invalid node ID: {}", e))?;
+    bytes
+        .try_into()
+        .map_err(|_| "Node ID must be 6 bytes, e.g. 00:1a:2b:3c:4d:5e".to_string())
+}
+
+/// A random node ID with the multicast bit set, so it can never collide with
+/// a real IEEE 802 MAC address (RFC 9562, section 6.10).
+fn random_node_id<R: Rng>(rng: &mut R) -> [u8; 6] {
+    let mut node_id: [u8; 6] = rng.gen();
+    node_id[0] |= 0x01;
+    node_id
+}
+
+fn extract_bits(value: u128, offset: u32, width: u32) -> u128 {
+    (value >> (128 - offset - width)) & (u128::MAX >> (128 - width))
+}
+
+fn make_field(value: u128, name: &str, offset: u32, width: u32) -> UuidField {
+    let bits = extract_bits(value, offset, width);
+    UuidField {
+        name: name.to_string(),
+        bit_offset: offset,
+        bit_width: width,
+        bits: format!("{:0width$b}", bits, width = width as usize),
+        hex: format!("{:0width$x}", bits, width = width.div_ceil(4) as usize),
+    }
+}
+
+/// The RFC 9562 layout of each version as (name, offset, width) triples.
+fn field_layout(version: usize) -> &'static [(&'static str, u32, u32)] {
+    match version {
+        1 => &[
+            ("time_low", 0, 32),
+            ("time_mid", 32, 16),
+            ("ver", 48, 4),
+            ("time_high", 52, 12),
+            ("var", 64, 2),
+            ("clock_seq", 66, 14),
+            ("node", 80, 48),
+        ],
+        6 => &[
+            ("time_high", 0, 32),
+            ("time_mid", 32, 16),
+            ("ver", 48, 4),
+            ("time_low", 52, 12),
+            ("var", 64, 2),
+            ("clock_seq", 66, 14),
+            ("node", 80, 48),
+        ],
+        7 => &[
+            ("unix_ts_ms", 0, 48),
+            ("ver", 48, 4),
+            ("rand_a", 52, 12),
+            ("var", 64, 2),
+            ("rand_b", 66, 62),
+        ],
+        3 | 5 => &[
+            ("hash_a", 0, 48),
+            ("ver", 48, 4),
+            ("hash_b", 52, 12),
+            ("var", 64, 2),
+            ("hash_c", 66, 62),
+        ],
+        4 => &[
+            ("random_a", 0, 48),
+            ("ver", 48, 4),
+            ("random_b", 52, 12),
+            ("var", 64, 2),
+            ("random_c", 66, 62),
+        ],
+        _ => &[
+            ("custom_a", 0, 48),
+            ("ver", 48, 4),
+            ("custom_b", 52, 12),
+            ("var", 64, 2),
+            ("custom_c", 66, 62),
+        ],
+    }
+}
+
+/// Concatenates the non-version, non-variant bits of the given fields.
+fn random_payload(value: u128, fields: &[(&str, u32, u32)]) -> String {
+    let (bits, width) = fields
+        .iter()
+        .filter(|(name, _, _)| name.starts_with("rand"))
+        .fold((0u128, 0u32), |(bits, width), &(_, offset, len)| {
+            ((bits << len) | extract_bits(value, offset, len), width + len)
+        });
+    format!("{:0width$x}", bits, width = width.div_ceil(4) as usize)
+}
+
+/// Converts 100ns ticks since the Unix epoch, which may be negative for v1
+/// and v6 UUIDs from before 1970, to RFC 3339 and Unix milliseconds.
+fn describe_unix_ticks(ticks: i128) -> (Option<String>, Option<i64>) {
+    let nanos = ticks * 100;
+    let seconds = nanos.div_euclid(1_000_000_000) as i64;
+    let subsec_nanos = nanos.rem_euclid(1_000_000_000) as u32;
+    let timestamp = DateTime::from_timestamp(seconds, subsec_nanos)
+        .map(|dt| dt.to_rfc3339_opts(SecondsFormat::AutoSi, true));
+    (timestamp, Some(nanos.div_euclid(1_000_000) as i64))
+}
+
+/// The embedded time of a v1, v6 or v7 UUID as 100ns ticks since the Unix epoch.
+fn unix_ticks(uuid: &Uuid) -> Option<i128> {
+    match uuid.get_version_num() {
+        1 | 6 => {
+            let (ticks, _) = uuid.get_timestamp()?.to_gregorian();
+            Some(ticks as i128 - GREGORIAN_UNIX_OFFSET as i128)
+        }
+        7 => Some(extract_bits(uuid.as_u128(), 0, 48) as i128 * 10_000),
+        _ => None,
+    }
+}
+
+fn low_bits_mask(width: u32) -> u128 {
+    u128::MAX >> (128 - width)
+}
+
+fn parse_field_value(value: Option<&str>) -> Result<u128, String> {
+    let value = value.map(str::trim).unwrap_or("0");
+    let parsed = match value.strip_prefix("0x").or_else(|| value.strip_prefix("0X")) {
+        Some(hex) => u128::from_str_radix(hex, 16),
+        None => value.parse::<u128>(),
+    };
+    parsed.map_err(|e| format!("Invalid field value '{}': {}", value, e))
+}
+
+/// Checks that a v8 template covers exactly the 122 payload bits.
+fn validate_template(fields: &[V8Field]) -> Result<(), String> {
+    if fields.is_empty() {
+        return Err("UUID v8 requires a layout template".to_string());
+    }
+    let mut total = 0;
+    for field in fields {
+        if field.width == 0 || field.width > V8_PAYLOAD_BITS {
+            return Err(format!(
+                "Field '{}' must be 1-{} bits wide",
+                field.name, V8_PAYLOAD_BITS
+            ));
+        }
+        match field.source.as_str() {
+            "timestamp" | "random" => {}
+            "counter" | "constant" => {
+                if parse_field_value(field.value.as_deref())? > low_bits_mask(field.width) {
+                    return Err(format!(
+                        "Value of field '{}' does not fit in {} bits",
+                        field.name, field.width
+                    ));
+                }
+            }
+            other => return Err(format!("Unsupported field source: {}", other)),
+        }
+        total += field.width;
+    }
+    if total != V8_PAYLOAD_BITS {
+        return Err(format!(
+            "Template fields cover {} bits; a v8 UUID has exactly {} custom bits",
+            total, V8_PAYLOAD_BITS
+        ));
+    }
+    Ok(())
+}
+
+/// Builds the `index`-th v8 UUID of a batch from a validated template.
+/// Timestamps keep their low bits when the field is narrower than the value,
+/// and counters wrap around within their width.
+fn build_v8<R: Rng>(
+    rng: &mut R,
+    fields: &[V8Field],
+    index: u128,
+    unix_millis: u128,
+) -> Result<Uuid, String> {
+    let mut payload = 0u128;
+    for field in fields {
+        let value = match field.source.as_str() {
+            "timestamp" => unix_millis,
+            "counter" => parse_field_value(field.value.as_deref())?.wrapping_add(index),
+            "constant" => parse_field_value(field.value.as_deref())?,
+            _ => rng.gen::<u128>(),
+        };
+        payload = (payload << field.width) | (value & low_bits_mask(field.width));
+    }
+
+    // Spread the payload around the version (bits 48-51) and variant (64-65)
+    let value = ((payload >> 74) << 80)
+        | (((payload >> 62) & 0xFFF) << 64)
+        | (payload & low_bits_mask(62));
+    Ok(Uuid::new_v8(value.to_be_bytes()))
+}
+
+/// Reads the fields of a v8 UUID back out according to `fields`.
+fn decode_v8(value: u128, fields: &[V8Field]) -> Vec<CustomField> {
+    let payload = (extract_bits(value, 0, 48) << 74)
+        | (extract_bits(value, 52, 12) << 62)
+        | extract_bits(value, 66, 62);
+    let mut remaining = V8_PAYLOAD_BITS;
+    fields
+        .iter()
+        .map(|field| {
+            remaining -= field.width;
+            let bits = (payload >> remaining) & low_bits_mask(field.width);
+            let timestamp = (field.source == "timestamp")
+                .then(|| describe_unix_ticks(bits as i128 * 10_000).0)
+                .flatten();
+            CustomField {
+                name: field.name.clone(),
+                source: field.source.clone(),
+                value: bits.to_string(),
+                hex: format!("{:x}", bits),
+                timestamp,
+            }
+        })
+        .collect()
+}
+
+/// Resolves a well-known namespace name or parses a custom namespace UUID.
+fn parse_namespace(namespace: Option<&str>) -> Result<Uuid, String> {
+    let namespace = namespace.map(str::trim).unwrap_or("dns");
+    match namespace.to_lowercase().as_str() {
+        "dns" => Ok(Uuid::NAMESPACE_DNS),
+        "url" => Ok(Uuid::NAMESPACE_URL),
+        "oid" => Ok(Uuid::NAMESPACE_OID),
+        "x500" => Ok(Uuid::NAMESPACE_X500),
+        _ => Uuid::parse_str(namespace).map_err(|e| format!("Invalid namespace UUID: {}", e)),
+    }
+}
+
+/// Parses and describes a UUID. v8 UUIDs are also split into the fields of
+/// `template` when one is given.
 #[tauri::command]
-pub fn parse_uuid(uuid: String) -> Result<ParsedUuid, String> {
+pub fn parse_uuid(uuid: String, template: Option<Vec<V8Field>>) -> Result<ParsedUuid, String> {
     let trimmed = uuid.trim();
     
     match Uuid::parse_str(trimmed) {
@@ -31,15 +403,10 @@ pub fn parse_uuid(uuid: String) -> Result<ParsedUuid, String> {
                 Some(Version::Md5) => "3 (MD5)".to_string(),
                 Some(Version::Sha1) => "5 (SHA-1)".to_string(),
                 Some(Version::Mac) => "1 (MAC address)".to_string(),
-                _ => {
-                    // Check if it's v7 by looking at the version number
-                    let version_num = parsed_uuid.get_version_num();
-                    if version_num == 7 {
-                        "7 (sortable random)".to_string()
-                    } else {
-                        format!("Version {}", version_num)
-                    }
-                }
+                Some(Version::SortMac) => "6 (reordered time)".to_string(),
+                Some(Version::SortRand) => "7 (Unix time-ordered)".to_string(),
+                Some(Version::Custom) => "8 (custom)".to_string(),
+                _ => format!("Version {}", parsed_uuid.get_version_num()),
             };
 
             let variant = match parsed_uuid.get_variant() {
@@ -50,61 +417,469 @@ pub fn parse_uuid(uuid: String) -> Result<ParsedUuid, String> {
                 _ => "Unknown".to_string(),
             };
 
-            Ok(ParsedUuid {
+            let mut parsed = ParsedUuid {
                 is_valid: true,
                 standard_format: parsed_uuid.hyphenated().to_string(),
                 raw_contents: parsed_uuid.simple().to_string(),
                 version,
                 variant,
                 error_message: None,
-            })
+                timestamp: None,
+                unix_millis: None,
+                clock_sequence: None,
+                node_id: None,
+                random_bits: None,
+                fields: Vec::new(),
+                custom_fields: Vec::new(),
+            };
+
+            // Field layouts only apply to RFC 9562 UUIDs, not the Nil and Max values
+            let version_num = parsed_uuid.get_version_num();
+            if parsed_uuid.get_variant() != uuid::Variant::RFC4122
+                || !(1..=8).contains(&version_num)
+            {
+                return Ok(parsed);
+            }
+            let value = parsed_uuid.as_u128();
+            let layout = field_layout(version_num);
+            parsed.fields = layout
+                .iter()
+                .map(|&(name, offset, width)| make_field(value, name, offset, width))
+                .collect();
+
+            match version_num {
+                1 | 6 => {
+                    if let Some(ticks) = unix_ticks(&parsed_uuid) {
+                        (parsed.timestamp, parsed.unix_millis) = describe_unix_ticks(ticks);
+                    }
+                    if let Some(ts) = parsed_uuid.get_timestamp() {
+                        parsed.clock_sequence = Some(ts.to_gregorian().1);
+                    }
+                    parsed.node_id = parsed_uuid.get_node_id().map(|node| {
+                        node.iter().map(|b| format!("{:02x}", b)).collect::<Vec<_>>().join(":")
+                    });
+                }
+                7 => {
+                    if let Some(ticks) = unix_ticks(&parsed_uuid) {
+                        (parsed.timestamp, parsed.unix_millis) = describe_unix_ticks(ticks);
+                    }
+                    parsed.random_bits = Some(random_payload(value, layout));
+                }
+                4 => parsed.random_bits = Some(random_payload(value, layout)),
+                8 => {
+                    if let Some(template) = &template {
+                        validate_template(template)?;
+                        parsed.custom_fields = decode_v8(value, template);
+                        let timestamp_field = parsed
+                            .custom_fields
+                            .iter()
+                            .find(|field| field.source == "timestamp");
+                        if let Some(field) = timestamp_field {
+                            let millis = field.value.parse::<i128>().unwrap_or(0);
+                            (parsed.timestamp, parsed.unix_millis) =
+                                describe_unix_ticks(millis * 10_000);
+                        }
+                    }
+                }
+                _ => {}
+            }
+
+            Ok(parsed)
         }
         Err(e) => Err(format!("Invalid UUID: {}", e)),
     }
 }
 
+/// Generates UUIDs of the given version. With a `seed`, random bits come from
+/// a ChaCha20 stream and time-based versions from a fixed clock, so the same
+/// options always produce the same UUIDs.
 #[tauri::command]
 pub fn generate_uuids(version: String, options: GenerateOptions) -> Result<Vec<String>, String> {
+    // Name-based UUIDs are deterministic: one per name instead of `count`
+    if version == "v3" || version == "v5" {
+        let namespace = parse_namespace(options.namespace.as_deref())?;
+        if options.names.is_empty() {
+            return Err("UUID v3 and v5 require at least one name".to_string());
+        }
+        return Ok(options
+            .names
+            .iter()
+            .map(|name| {
+                let uuid = if version == "v3" {
+                    Uuid::new_v3(&namespace, name.as_bytes())
+                } else {
+                    Uuid::new_v5(&namespace, name.as_bytes())
+                };
+                format_uuid(&uuid, &options.format)
+            })
+            .collect());
+    }
+
+    let mut rng = id_rng(options.seed);
+    let clock = IdClock::new(options.seed, options.timestamp);
+
+    if version == "v8" {
+        let template = options.template.as_deref().unwrap_or_default();
+        validate_template(template)?;
+        let unix_millis = clock.now_millis().max(0) as u128;
+        return (0..options.count)
+            .map(|index| {
+                build_v8(&mut rng, template, index as u128, unix_millis)
+                    .map(|uuid| format_uuid(&uuid, &options.format))
+            })
+            .collect();
+    }
+
+    // Seeded v7 UUIDs can't come from `ContextV7`, whose counter is
+    // randomly initialised
+    if version == "v7" && clock.is_fixed() {
+        return Ok(monotonic_v7s(&mut rng, clock, options.count)?
+            .iter()
+            .map(|uuid| format_uuid(uuid, &options.format))
+            .collect());
+    }
+
     let mut results = Vec::with_capacity(options.count);
-    
+    // Shared across the batch so v7 UUIDs generated within the same
+    // millisecond still sort in generation order
+    let v7_context = ContextV7::new();
+
+    // v1/v6 use one node and clock sequence for the whole batch, so each
+    // UUID gets its own 100ns tick to stay unique
+    let node_id = match options.node_id.as_deref().filter(|id| !id.trim().is_empty()) {
+        Some(id) => parse_node_id(id)?,
+        None => random_node_id(&mut rng),
+    };
+    let clock_sequence = match options.clock_sequence {
+        Some(seq) if seq > 0x3FFF => {
+            return Err("Clock sequence must fit in 14 bits (0-16383)".to_string())
+        }
+        Some(seq) => seq,
+        None => rng.gen_range(0..=0x3FFF),
+    };
+    let mut last_ticks = 0u64;
+    let mut next_timestamp = || {
+        let ticks = if clock.is_fixed() {
+            (clock.now_millis().max(0) as u64) * 10_000 + GREGORIAN_UNIX_OFFSET
+        } else {
+            let now = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default();
+            (now.as_nanos() / 100) as u64 + GREGORIAN_UNIX_OFFSET
+        };
+        last_ticks = ticks.max(last_ticks + 1);
+        Timestamp::from_gregorian_time(last_ticks, clock_sequence)
+    };
+
     for _ in 0..options.count {
         let uuid = match version.as_str() {
-            "v1" => {
-                // Generate v1 UUID with a dummy node ID
-                // Note: This requires the v1 feature which we have enabled
-                let node_id = [1, 2, 3, 4, 5, 6]; // Dummy MAC address
-                Uuid::new_v1(
-                    uuid::Timestamp::now(uuid::NoContext),
-                    &node_id
-                )
-            }
-            "v3" => {
-                // Using DNS namespace with example.com
-                Uuid::new_v3(&Uuid::NAMESPACE_DNS, b"example.com")
-            }
-            "v4" => Uuid::new_v4(),
-            "v5" => {
-                // Using DNS namespace with example.com
-                Uuid::new_v5(&Uuid::NAMESPACE_DNS, b"example.com")
+            "v1" => Uuid::new_v1(next_timestamp(), &node_id),
+            "v4" => Builder::from_random_bytes(rng.gen()).into_uuid(),
+            "v6" => Uuid::new_v6(next_timestamp(), &node_id),
+            "v7" => Uuid::new_v7(Timestamp::now(&v7_context)),
+            _ => return Err("Unsupported UUID version".to_string()),
+        };
+
+        results.push(format_uuid(&uuid, &options.format));
+    }
+    
+    Ok(results)
+}
+
+/// Reorders a GUID between RFC byte order and the mixed-endian order used by
+/// .NET, where the first three fields are little-endian. The swap is its own
+/// inverse.
+fn swap_guid_bytes(bytes: [u8; 16]) -> [u8; 16] {
+    let mut swapped = bytes;
+    swapped[0..4].reverse();
+    swapped[4..6].reverse();
+    swapped[6..8].reverse();
+    swapped
+}
+
+/// Moves time_hi and time_mid in front of time_low, as MySQL's
+/// `UUID_TO_BIN(uuid, 1)` does to make v1 UUIDs index-friendly.
+fn mysql_swap(bytes: [u8; 16]) -> [u8; 16] {
+    let mut swapped = [0u8; 16];
+    swapped[0..2].copy_from_slice(&bytes[6..8]);
+    swapped[2..4].copy_from_slice(&bytes[4..6]);
+    swapped[4..8].copy_from_slice(&bytes[0..4]);
+    swapped[8..].copy_from_slice(&bytes[8..]);
+    swapped
+}
+
+/// The inverse of `mysql_swap`, as done by `BIN_TO_UUID(bin, 1)`.
+fn mysql_unswap(bytes: [u8; 16]) -> [u8; 16] {
+    let mut unswapped = [0u8; 16];
+    unswapped[0..4].copy_from_slice(&bytes[4..8]);
+    unswapped[4..6].copy_from_slice(&bytes[2..4]);
+    unswapped[6..8].copy_from_slice(&bytes[0..2]);
+    unswapped[8..].copy_from_slice(&bytes[8..]);
+    unswapped
+}
+
+fn to_uuid_bytes(bytes: Vec<u8>) -> Result<[u8; 16], String> {
+    let len = bytes.len();
+    bytes
+        .try_into()
+        .map_err(|_| format!("Expected 16 bytes, got {}", len))
+}
+
+/// Parses a byte array such as `{0x12, 0x34, ...}` or a Java-style
+/// `[18, -52, ...]` with signed or unsigned decimal bytes.
+fn parse_byte_array(text: &str) -> Result<[u8; 16], String> {
+    let inner = text.trim_matches(|c| matches!(c, '[' | ']' | '{' | '}' | '(' | ')'));
+    let bytes = inner
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let value = match part.strip_prefix("0x").or_else(|| part.strip_prefix("0X")) {
+                Some(hex) => i16::from_str_radix(hex, 16),
+                None => part.parse::<i16>(),
             }
-            "v7" => {
-                // For now, use v4 as a fallback since v7 might not be available
-                // TODO: Update when uuid crate supports v7
-                Uuid::new_v4()
+            .map_err(|_| format!("Invalid byte: {}", part))?;
+            match value {
+                -128..=-1 => Ok(value as i8 as u8),
+                0..=255 => Ok(value as u8),
+                _ => Err(format!("Byte out of range: {}", part)),
             }
-            _ => return Err("Unsupported UUID version".to_string()),
+        })
+        .collect::<Result<Vec<u8>, String>>()?;
+    to_uuid_bytes(bytes)
+}
+
+fn parse_decimal(text: &str) -> Result<Uuid, String> {
+    if !text.chars().all(|c| c.is_ascii_digit()) {
+        return Err("Decimal input may only contain digits".to_string());
+    }
+    let value = text
+        .parse::<u128>()
+        .map_err(|_| "Decimal value exceeds 128 bits".to_string())?;
+    Ok(Uuid::from_u128(value))
+}
+
+/// Recognises a UUID in any of the representations `convert_uuid` emits.
+/// Formats that can't be told apart from plain hex or bytes (`mysql-swap`,
+/// `guid-bytes`) must be named in `input_format`, as must a 32-digit
+/// `decimal`, which auto-detection reads as simple hex.
+fn parse_any_uuid(text: &str, input_format: &str) -> Result<(Uuid, String), String> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Err("Input is empty".to_string());
+    }
+
+    match input_format {
+        "mysql-swap" => {
+            let hex = text.trim_start_matches("0x").trim_start_matches("0X");
+            let bytes = hex::decode(hex).map_err(|e| format!("Invalid hex: {}", e))?;
+            let bytes = mysql_unswap(to_uuid_bytes(bytes)?);
+            return Ok((Uuid::from_bytes(bytes), "MySQL UUID_TO_BIN(swap)".to_string()));
+        }
+        "guid-bytes" => {
+            let bytes = if text.contains(',') {
+                parse_byte_array(text)?
+            } else {
+                let hex = hex::decode(text).map_err(|e| format!("Invalid hex: {}", e))?;
+                to_uuid_bytes(hex)?
+            };
+            return Ok((Uuid::from_bytes(swap_guid_bytes(bytes)), ".NET GUID bytes".to_string()));
+        }
+        "decimal" => return Ok((parse_decimal(text)?, "Decimal".to_string())),
+        "auto" | "" => {}
+        _ => return Err(format!("Unsupported input format: {}", input_format)),
+    }
+
+    if let Ok(uuid) = Uuid::parse_str(text) {
+        let format = match text.len() {
+            32 => "Simple",
+            36 => "Hyphenated",
+            38 => "Braced",
+            _ => "URN",
         };
+        return Ok((uuid, format.to_string()));
+    }
+    if text.contains(',') {
+        return Ok((Uuid::from_bytes(parse_byte_array(text)?), "Byte array".to_string()));
+    }
+    if let Some(hex) = text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+        let bytes = hex::decode(hex).map_err(|e| format!("Invalid hex: {}", e))?;
+        return Ok((Uuid::from_bytes(to_uuid_bytes(bytes)?), "Hex".to_string()));
+    }
+    if text.chars().all(|c| c.is_ascii_digit()) {
+        return Ok((parse_decimal(text)?, "Decimal".to_string()));
+    }
+
+    let decoded = match text.len() {
+        22 if text.contains(['-', '_']) => {
+            Some((BASE64URL_NOPAD.decode(text.as_bytes()), "Base64url"))
+        }
+        22 => Some((BASE64_NOPAD.decode(text.as_bytes()), "Base64")),
+        24 => Some((BASE64.decode(text.as_bytes()), "Base64")),
+        26 => {
+            let value = decode_crockford(text).map_err(|e| format!("Invalid ULID: {}", e))?;
+            return Ok((Uuid::from_u128(value), "Crockford base32 (ULID)".to_string()));
+        }
+        32 => Some((BASE32.decode(text.to_uppercase().as_bytes()), "Base32")),
+        _ => None,
+    };
+    match decoded {
+        Some((Ok(bytes), format)) => {
+            Ok((Uuid::from_bytes(to_uuid_bytes(bytes)?), format.to_string()))
+        }
+        Some((Err(e), format)) => Err(format!("Invalid {}: {}", format, e)),
+        None => Err("Unrecognised UUID representation".to_string()),
+    }
+}
+
+fn format_hex_bytes(bytes: &[u8]) -> String {
+    let parts: Vec<String> = bytes.iter().map(|b| format!("0x{:02x}", b)).collect();
+    format!("{{{}}}", parts.join(", "))
+}
 
-        let formatted = match options.format.as_str() {
-            "standard" => uuid.hyphenated().to_string(),
-            "no-hyphens" => uuid.simple().to_string(),
-            "uppercase" => uuid.hyphenated().to_string().to_uppercase(),
-            "uppercase-no-hyphens" => uuid.simple().to_string().to_uppercase(),
-            _ => uuid.hyphenated().to_string(),
+/// Converts a UUID given in any supported representation into all of them.
+/// `input_format` defaults to auto-detection.
+#[tauri::command]
+pub fn convert_uuid(uuid: String, input_format: Option<String>) -> Result<UuidConversions, String> {
+    let (uuid, input_format) = parse_any_uuid(&uuid, input_format.as_deref().unwrap_or("auto"))?;
+    let bytes = *uuid.as_bytes();
+    let java_bytes: Vec<String> = bytes.iter().map(|&b| (b as i8).to_string()).collect();
+
+    Ok(UuidConversions {
+        input_format,
+        hyphenated: uuid.hyphenated().to_string(),
+        simple: uuid.simple().to_string(),
+        braced: uuid.braced().to_string(),
+        urn: uuid.urn().to_string(),
+        base64: BASE64.encode(&bytes),
+        base64_url: BASE64URL_NOPAD.encode(&bytes),
+        base32: BASE32.encode(&bytes),
+        crockford: encode_crockford(uuid.as_u128()),
+        decimal: uuid.as_u128().to_string(),
+        bytes: format_hex_bytes(&bytes),
+        java_bytes: format!("{{{}}}", java_bytes.join(", ")),
+        guid_bytes: format_hex_bytes(&swap_guid_bytes(bytes)),
+        mysql_swap_hex: hex::encode_upper(mysql_swap(bytes)),
+    })
+}
+
+fn version_group(uuid: &Uuid) -> (u8, String) {
+    if uuid.is_nil() {
+        return (0, "Nil".to_string());
+    }
+    if uuid.is_max() {
+        return (15, "Max".to_string());
+    }
+    if uuid.get_variant() != uuid::Variant::RFC4122 {
+        return (16, format!("{:?} variant", uuid.get_variant()));
+    }
+    let version = uuid.get_version_num() as u8;
+    (version, format!("v{}", version))
+}
+
+fn analyze_uuid_lines(text: &str) -> UuidListAnalysis {
+    let mut analysis = UuidListAnalysis {
+        total_lines: 0,
+        valid_count: 0,
+        unique_count: 0,
+        invalid_lines: Vec::new(),
+        duplicates: Vec::new(),
+        versions: Vec::new(),
+        earliest: None,
+        latest: None,
+        is_sorted: None,
+        first_unsorted_line: None,
+    };
+    // Keyed by UUID so differently-cased copies count as duplicates;
+    // the Vec keeps first-seen order for the report
+    let mut seen: HashMap<Uuid, usize> = HashMap::new();
+    let mut occurrences: Vec<(Uuid, Vec<usize>)> = Vec::new();
+    let mut versions: HashMap<u8, (String, usize)> = HashMap::new();
+    let mut earliest: Option<(i128, usize, Uuid)> = None;
+    let mut latest: Option<(i128, usize, Uuid)> = None;
+    let mut previous_ticks: Option<i128> = None;
+
+    for (index, raw_line) in text.lines().enumerate() {
+        let line = index + 1;
+        // Tolerate quotes and trailing separators from SQL or CSV extracts
+        let value = raw_line.trim().trim_matches(|c| matches!(c, '"' | '\'' | ',' | ';'));
+        if value.is_empty() {
+            continue;
+        }
+        analysis.total_lines += 1;
+
+        let uuid = match Uuid::parse_str(value) {
+            Ok(uuid) => uuid,
+            Err(e) => {
+                analysis.invalid_lines.push(InvalidUuidLine {
+                    line,
+                    text: raw_line.to_string(),
+                    error: e.to_string(),
+                });
+                continue;
+            }
         };
+        analysis.valid_count += 1;
+
+        match seen.get(&uuid) {
+            Some(&position) => occurrences[position].1.push(line),
+            None => {
+                seen.insert(uuid, occurrences.len());
+                occurrences.push((uuid, vec![line]));
+            }
+        }
+
+        let (order, label) = version_group(&uuid);
+        versions.entry(order).or_insert((label, 0)).1 += 1;
 
-        results.push(formatted);
+        if let Some(ticks) = unix_ticks(&uuid) {
+            if earliest.is_none_or(|(min, _, _)| ticks < min) {
+                earliest = Some((ticks, line, uuid));
+            }
+            if latest.is_none_or(|(max, _, _)| ticks > max) {
+                latest = Some((ticks, line, uuid));
+            }
+            if previous_ticks.is_some_and(|previous| ticks < previous)
+                && analysis.first_unsorted_line.is_none()
+            {
+                analysis.first_unsorted_line = Some(line);
+            }
+            previous_ticks = Some(ticks);
+        }
     }
-    
-    Ok(results)
+
+    analysis.unique_count = occurrences.len();
+    analysis.duplicates = occurrences
+        .into_iter()
+        .filter(|(_, lines)| lines.len() > 1)
+        .map(|(uuid, lines)| DuplicateUuid {
+            uuid: uuid.hyphenated().to_string(),
+            lines,
+        })
+        .collect();
+
+    let mut versions: Vec<_> = versions.into_iter().collect();
+    versions.sort_by_key(|(order, _)| *order);
+    analysis.versions = versions
+        .into_iter()
+        .map(|(_, (version, count))| UuidVersionCount { version, count })
+        .collect();
+
+    let describe = |(ticks, line, uuid): (i128, usize, Uuid)| TimestampedUuid {
+        line,
+        uuid: uuid.hyphenated().to_string(),
+        timestamp: describe_unix_ticks(ticks).0,
+    };
+    analysis.is_sorted = previous_ticks.map(|_| analysis.first_unsorted_line.is_none());
+    analysis.earliest = earliest.map(describe);
+    analysis.latest = latest.map(describe);
+    analysis
+}
+
+/// Validates a list of UUIDs, one per line, reporting invalid lines,
+/// duplicates, versions and the time span of time-based UUIDs.
+#[tauri::command]
+pub async fn analyze_uuids(text: String) -> Result<UuidListAnalysis, String> {
+    tauri::async_runtime::spawn_blocking(move || analyze_uuid_lines(&text))
+        .await
+        .map_err(|e| format!("UUID analysis failed: {}", e))
 }
@@ -1,10 +1,15 @@
 import React, { useState, useRef } from "react";
-import { Search, Binary, FileText, Hash, List, Shield, Braces, Fingerprint, KeySquare, Sun, Moon, Monitor, Link, Type, Clock, CalendarClock, Code2, FileJson, Space } from "lucide-react";
+import { Search, Binary, FileText, Hash, List, Shield, Braces, Fingerprint, KeySquare, Sun, Moon, Monitor, Link, Type, Clock, CalendarClock, Code2, FileJson, Space, KeyRound, FileCheck, FolderTree, GitCompare, LockKeyhole } from "lucide-react";
 import { NumberBaseConverter } from "./components/NumberBaseConverter";
 import { Base64EncoderDecoder } from "./components/Base64EncoderDecoder";
 import { Base58EncoderDecoder } from "./components/Base58EncoderDecoder";
 import { MultiLineToJsonArray } from "./components/MultiLineToJsonArray";
 import { HashGenerator } from "./components/HashGenerator";
+import { HmacTool } from "./components/HmacTool";
+import { ChecksumVerifier } from "./components/ChecksumVerifier";
+import { DirectoryHasher } from "./components/DirectoryHasher";
+import { HashComparator } from "./components/HashComparator";
+import { PasswordHasher } from "./components/PasswordHasher";
 import { JsonFormatter } from "./components/JsonFormatter";
 import UuidGenerator from "./components/UuidGenerator";
 import JwtTool from "./components/JwtTool";
@@ -66,6 +71,42 @@ const tools: Tool[] = [
     icon: Shield,
     component: HashGenerator,
   },
+  {
+    id: "hmac-tool",
+    name: "HMAC Generator",
+    description: "Generate and verify HMAC signatures, including GitHub, Stripe and Slack webhooks",
+    icon: KeyRound,
+    component: HmacTool,
+  },
+  {
+    id: "checksum-verifier",
+    name: "Checksum Files",
+    title: "Checksum File Verify & Generate",
+    description: "Verify files against sha256sum/SHA256SUMS manifests or write new ones",
+    icon: FileCheck,
+    component: ChecksumVerifier,
+  },
+  {
+    id: "directory-hasher",
+    name: "Directory Hasher",
+    description: "Hash every file in a folder and compute a single root hash for the tree",
+    icon: FolderTree,
+    component: DirectoryHasher,
+  },
+  {
+    id: "hash-comparator",
+    name: "Hash Comparator",
+    description: "Check a file against an expected hash or compare two files",
+    icon: GitCompare,
+    component: HashComparator,
+  },
+  {
+    id: "password-hasher",
+    name: "Password Hasher",
+    description: "Hash and verify passwords with Argon2, bcrypt, scrypt and PBKDF2",
+    icon: LockKeyhole,
+    component: PasswordHasher,
+  },
   {
     id: "json-formatter",
     name: "JSON Formatter & Validator",
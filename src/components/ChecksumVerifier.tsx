@@ -0,0 +1,279 @@
+import { useState } from "react";
+import { Copy, Download } from "lucide-react";
+import { invoke } from "@tauri-apps/api/core";
+import { open } from '@tauri-apps/plugin-dialog';
+
+// Algorithms with a BSD tag, so both manifest formats can be written
+const checksumAlgorithms = [
+  { name: "MD5", key: "md5" },
+  { name: "SHA1", key: "sha1" },
+  { name: "SHA224", key: "sha224" },
+  { name: "SHA256", key: "sha256" },
+  { name: "SHA384", key: "sha384" },
+  { name: "SHA512", key: "sha512" },
+  { name: "SHA3-256", key: "sha3-256" },
+  { name: "SHA3-512", key: "sha3-512" },
+  { name: "BLAKE2b-512", key: "blake2b-512" },
+  { name: "BLAKE2s-256", key: "blake2s-256" },
+  { name: "BLAKE3", key: "blake3" },
+];
+
+const manifestFormats = [
+  { name: "GNU (sha256sum)", key: "gnu" },
+  { name: "BSD (SHA256 (file) = ...)", key: "bsd" },
+];
+
+type ChecksumStatus = "OK" | "FAILED" | "MISSING";
+
+interface ChecksumEntry {
+  fileName: string;
+  algorithm: string;
+  expected: string;
+  actual: string | null;
+  status: ChecksumStatus;
+  error: string | null;
+}
+
+interface ChecksumReport {
+  entries: ChecksumEntry[];
+  okCount: number;
+  failedCount: number;
+  missingCount: number;
+  skippedLines: number[];
+}
+
+const statusClass: Record<ChecksumStatus, string> = {
+  OK: "text-green-500",
+  FAILED: "text-red-500",
+  MISSING: "text-orange-500",
+};
+
+const selectClass =
+  "px-2 py-2 bg-tertiary border border-primary rounded-lg text-primary text-xs focus:outline-none focus:ring-2 focus:ring-blue-500";
+const buttonClass =
+  "px-3 py-2 text-xs bg-tertiary hover:bg-secondary border border-primary text-primary rounded-lg transition-colors disabled:opacity-50";
+
+const fileNameOf = (path: string) => path.split("/").pop() || path.split("\\").pop() || path;
+
+export function ChecksumVerifier() {
+  const [manifestPath, setManifestPath] = useState<string | null>(null);
+  const [verifyAlgorithm, setVerifyAlgorithm] = useState("");
+  const [report, setReport] = useState<ChecksumReport | null>(null);
+  const [isVerifying, setIsVerifying] = useState(false);
+  const [verifyError, setVerifyError] = useState<string | null>(null);
+
+  const [files, setFiles] = useState<string[]>([]);
+  const [algorithm, setAlgorithm] = useState("sha256");
+  const [format, setFormat] = useState("gnu");
+  const [manifest, setManifest] = useState("");
+  const [isGenerating, setIsGenerating] = useState(false);
+  const [generateError, setGenerateError] = useState<string | null>(null);
+
+  const verify = async (path: string, algorithmHint: string) => {
+    setIsVerifying(true);
+    setVerifyError(null);
+    try {
+      const result = await invoke<ChecksumReport>("verify_checksum_file", {
+        manifestPath: path,
+        algorithm: algorithmHint || null,
+      });
+      setReport(result);
+    } catch (error) {
+      setReport(null);
+      setVerifyError(String(error));
+    } finally {
+      setIsVerifying(false);
+    }
+  };
+
+  const handleManifestLoad = async () => {
+    const selected = await open({ multiple: false, directory: false });
+    if (selected && typeof selected === "string") {
+      setManifestPath(selected);
+      await verify(selected, verifyAlgorithm);
+    }
+  };
+
+  const handleFilesSelect = async () => {
+    const selected = await open({ multiple: true, directory: false });
+    if (Array.isArray(selected)) {
+      setFiles(selected);
+    } else if (typeof selected === "string") {
+      setFiles([selected]);
+    }
+  };
+
+  const generate = async () => {
+    setIsGenerating(true);
+    setGenerateError(null);
+    try {
+      setManifest(await invoke<string>("generate_checksum_file", { paths: files, algorithm, format }));
+    } catch (error) {
+      setManifest("");
+      setGenerateError(String(error));
+    } finally {
+      setIsGenerating(false);
+    }
+  };
+
+  const copyToClipboard = async (text: string) => {
+    try {
+      await navigator.clipboard.writeText(text);
+    } catch (error) {
+      console.error("Failed to copy to clipboard:", error);
+    }
+  };
+
+  const downloadManifest = () => {
+    const blob = new Blob([manifest], { type: "text/plain" });
+    const url = URL.createObjectURL(blob);
+    const a = document.createElement("a");
+    a.href = url;
+    a.download = `${algorithm.replace(/-.*$/, "").toUpperCase()}SUMS`;
+    document.body.appendChild(a);
+    a.click();
+    document.body.removeChild(a);
+    URL.revokeObjectURL(url);
+  };
+
+  return (
+    <div className="w-full h-full">
+      <div className="w-full px-4 py-6 space-y-8">
+        {/* Verify */}
+        <section>
+          <div className="flex items-center justify-between mb-4">
+            <div className="flex items-center gap-3">
+              <span className="text-sm font-medium text-primary">Verify a checksum file</span>
+              <span className="text-sm text-secondary">{manifestPath ? fileNameOf(manifestPath) : "No file loaded"}</span>
+            </div>
+            <div className="flex items-center gap-3">
+              <select
+                value={verifyAlgorithm}
+                onChange={(e) => setVerifyAlgorithm(e.target.value)}
+                className={selectClass}
+                title="Algorithm for lines without a BSD tag"
+              >
+                <option value="">Auto-detect</option>
+                {checksumAlgorithms.map((algo) => (
+                  <option key={algo.key} value={algo.key}>
+                    {algo.name}
+                  </option>
+                ))}
+              </select>
+              <button onClick={handleManifestLoad} disabled={isVerifying} className={buttonClass}>
+                Load checksum file...
+              </button>
+              <button
+                onClick={() => manifestPath && verify(manifestPath, verifyAlgorithm)}
+                disabled={!manifestPath || isVerifying}
+                className={buttonClass}
+              >
+                {isVerifying ? "Verifying..." : "Re-verify"}
+              </button>
+            </div>
+          </div>
+
+          {verifyError && <p className="text-xs text-red-500 mb-3">{verifyError}</p>}
+
+          {report && (
+            <>
+              <div className="flex items-center gap-4 text-sm mb-3">
+                <span className="text-green-500">{report.okCount} OK</span>
+                <span className="text-red-500">{report.failedCount} failed</span>
+                <span className="text-orange-500">{report.missingCount} missing</span>
+                {report.skippedLines.length > 0 && (
+                  <span className="text-tertiary">
+                    Skipped line{report.skippedLines.length > 1 ? "s" : ""} {report.skippedLines.join(", ")}
+                  </span>
+                )}
+              </div>
+              <div className="space-y-2">
+                {report.entries.map((entry, index) => (
+                  <div
+                    key={`${entry.fileName}-${index}`}
+                    className="grid grid-cols-[72px_1fr_96px] items-center gap-4 px-3 py-2 bg-secondary border border-primary rounded-lg"
+                  >
+                    <span className={`text-xs font-medium ${statusClass[entry.status]}`}>{entry.status}</span>
+                    <div className="min-w-0">
+                      <div className="text-sm text-primary truncate" title={entry.fileName}>{entry.fileName}</div>
+                      {entry.status === "FAILED" && entry.actual && (
+                        <div className="text-xs text-tertiary font-mono truncate" title={entry.actual}>
+                          got {entry.actual}
+                        </div>
+                      )}
+                      {entry.error && <div className="text-xs text-tertiary truncate">{entry.error}</div>}
+                    </div>
+                    <span className="text-xs text-secondary text-right">{entry.algorithm}</span>
+                  </div>
+                ))}
+              </div>
+            </>
+          )}
+        </section>
+
+        {/* Generate */}
+        <section>
+          <div className="flex items-center justify-between mb-4">
+            <div className="flex items-center gap-3">
+              <span className="text-sm font-medium text-primary">Generate a checksum file</span>
+              <span className="text-sm text-secondary">
+                {files.length === 0 ? "No files selected" : `${files.length} file${files.length > 1 ? "s" : ""} selected`}
+              </span>
+            </div>
+            <div className="flex items-center gap-3">
+              <select value={algorithm} onChange={(e) => setAlgorithm(e.target.value)} className={selectClass} title="Algorithm">
+                {checksumAlgorithms.map((algo) => (
+                  <option key={algo.key} value={algo.key}>
+                    {algo.name}
+                  </option>
+                ))}
+              </select>
+              <select value={format} onChange={(e) => setFormat(e.target.value)} className={selectClass} title="Format">
+                {manifestFormats.map((fmt) => (
+                  <option key={fmt.key} value={fmt.key}>
+                    {fmt.name}
+                  </option>
+                ))}
+              </select>
+              <button onClick={handleFilesSelect} disabled={isGenerating} className={buttonClass}>
+                Select files...
+              </button>
+              <button onClick={generate} disabled={files.length === 0 || isGenerating} className={buttonClass}>
+                {isGenerating ? "Hashing..." : "Generate"}
+              </button>
+            </div>
+          </div>
+
+          {generateError && <p className="text-xs text-red-500 mb-3">{generateError}</p>}
+
+          <div className="relative">
+            <textarea
+              value={manifest}
+              readOnly
+              placeholder="Checksum file contents will appear here..."
+              className="w-full h-48 px-4 py-3 bg-secondary border border-primary rounded-lg text-primary placeholder-tertiary font-mono text-sm resize-none cursor-default"
+            />
+            <div className="absolute top-2 right-2 flex gap-1">
+              <button
+                onClick={() => copyToClipboard(manifest)}
+                className="p-2.5 text-tertiary hover:text-primary hover:bg-tertiary rounded-md transition-colors"
+                disabled={!manifest}
+                title="Copy to clipboard"
+              >
+                <Copy className="w-4 h-4" />
+              </button>
+              <button
+                onClick={downloadManifest}
+                className="p-2.5 text-tertiary hover:text-primary hover:bg-tertiary rounded-md transition-colors"
+                disabled={!manifest}
+                title="Save checksum file"
+              >
+                <Download className="w-4 h-4" />
+              </button>
+            </div>
+          </div>
+        </section>
+      </div>
+    </div>
+  );
+}
@@ -0,0 +1,223 @@
+import { useState } from "react";
+import { Copy } from "lucide-react";
+import { invoke } from "@tauri-apps/api/core";
+import { open } from '@tauri-apps/plugin-dialog';
+
+const directoryAlgorithms = [
+  { name: "MD5", key: "md5" },
+  { name: "SHA1", key: "sha1" },
+  { name: "SHA256", key: "sha256" },
+  { name: "SHA512", key: "sha512" },
+  { name: "SHA3-256", key: "sha3-256" },
+  { name: "BLAKE2b-512", key: "blake2b-512" },
+  { name: "BLAKE3", key: "blake3" },
+  { name: "XXH3-128", key: "xxh3-128" },
+];
+
+interface FileDigest {
+  path: string;
+  size: number;
+  hash: string;
+}
+
+interface DirectoryHashResult {
+  algorithm: string;
+  rootHash: string;
+  fileCount: number;
+  totalBytes: number;
+  files: FileDigest[];
+}
+
+const formatBytes = (bytes: number) => {
+  const units = ["B", "KB", "MB", "GB", "TB"];
+  let value = bytes;
+  let unit = 0;
+  while (value >= 1024 && unit < units.length - 1) {
+    value /= 1024;
+    unit++;
+  }
+  return `${value.toFixed(unit === 0 ? 0 : 1)} ${units[unit]}`;
+};
+
+// Globs are entered one per line or comma-separated
+const parseGlobs = (text: string) =>
+  text
+    .split(/[\n,]/)
+    .map((glob) => glob.trim())
+    .filter((glob) => glob.length > 0);
+
+const selectClass =
+  "px-2 py-2 bg-tertiary border border-primary rounded-lg text-primary text-xs focus:outline-none focus:ring-2 focus:ring-blue-500";
+const buttonClass =
+  "px-3 py-2 text-xs bg-tertiary hover:bg-secondary border border-primary text-primary rounded-lg transition-colors disabled:opacity-50";
+const globClass =
+  "w-full h-20 px-3 py-2 bg-tertiary border border-primary rounded-lg text-primary placeholder-tertiary focus:outline-none focus:ring-2 focus:ring-blue-500 focus:border-blue-500 font-mono text-xs resize-none";
+
+export function DirectoryHasher() {
+  const [directory, setDirectory] = useState<string | null>(null);
+  const [algorithm, setAlgorithm] = useState("sha256");
+  const [include, setInclude] = useState("");
+  const [exclude, setExclude] = useState("");
+  const [respectGitignore, setRespectGitignore] = useState(true);
+  const [includeHidden, setIncludeHidden] = useState(false);
+  const [result, setResult] = useState<DirectoryHashResult | null>(null);
+  const [isHashing, setIsHashing] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+
+  const hashDirectory = async (path: string) => {
+    setIsHashing(true);
+    setError(null);
+    try {
+      const options = {
+        algorithm,
+        include: parseGlobs(include),
+        exclude: parseGlobs(exclude),
+        respectGitignore,
+        includeHidden,
+      };
+      setResult(await invoke<DirectoryHashResult>("hash_directory", { path, options }));
+    } catch (err) {
+      setResult(null);
+      setError(String(err));
+    } finally {
+      setIsHashing(false);
+    }
+  };
+
+  const handleDirectorySelect = async () => {
+    const selected = await open({ multiple: false, directory: true });
+    if (selected && typeof selected === "string") {
+      setDirectory(selected);
+      await hashDirectory(selected);
+    }
+  };
+
+  const copyToClipboard = async (text: string) => {
+    try {
+      await navigator.clipboard.writeText(text);
+    } catch (err) {
+      console.error("Failed to copy to clipboard:", err);
+    }
+  };
+
+  return (
+    <div className="w-full h-full">
+      <div className="w-full px-4 py-6">
+        {/* Header Controls */}
+        <div className="flex items-center justify-between mb-6">
+          <div className="flex items-center gap-3">
+            <button onClick={handleDirectorySelect} disabled={isHashing} className={buttonClass}>
+              Select folder...
+            </button>
+            <button
+              onClick={() => directory && hashDirectory(directory)}
+              disabled={!directory || isHashing}
+              className={buttonClass}
+            >
+              {isHashing ? "Hashing..." : "Re-hash"}
+            </button>
+            <span className="text-sm text-secondary truncate" title={directory ?? undefined}>
+              {directory ?? "No folder selected"}
+            </span>
+          </div>
+          <div className="flex items-center gap-3">
+            <select value={algorithm} onChange={(e) => setAlgorithm(e.target.value)} className={selectClass} title="Algorithm">
+              {directoryAlgorithms.map((algo) => (
+                <option key={algo.key} value={algo.key}>
+                  {algo.name}
+                </option>
+              ))}
+            </select>
+            <label className="flex items-center gap-2 cursor-pointer">
+              <input
+                type="checkbox"
+                checked={respectGitignore}
+                onChange={(e) => setRespectGitignore(e.target.checked)}
+                className="w-4 h-4 text-blue-600 bg-secondary border-primary rounded focus:ring-blue-500"
+              />
+              <span className="text-sm text-primary">.gitignore</span>
+            </label>
+            <label className="flex items-center gap-2 cursor-pointer">
+              <input
+                type="checkbox"
+                checked={includeHidden}
+                onChange={(e) => setIncludeHidden(e.target.checked)}
+                className="w-4 h-4 text-blue-600 bg-secondary border-primary rounded focus:ring-blue-500"
+              />
+              <span className="text-sm text-primary">hidden files</span>
+            </label>
+          </div>
+        </div>
+
+        {/* Filters */}
+        <div className="grid grid-cols-2 gap-4 mb-6">
+          <div>
+            <span className="block text-sm text-secondary mb-2">Include globs</span>
+            <textarea
+              value={include}
+              onChange={(e) => setInclude(e.target.value)}
+              placeholder={"*.rs\nsrc/**"}
+              className={globClass}
+            />
+          </div>
+          <div>
+            <span className="block text-sm text-secondary mb-2">Exclude globs</span>
+            <textarea
+              value={exclude}
+              onChange={(e) => setExclude(e.target.value)}
+              placeholder={"target\nnode_modules"}
+              className={globClass}
+            />
+          </div>
+        </div>
+
+        {error && <p className="text-xs text-red-500 mb-4">{error}</p>}
+
+        {result && (
+          <>
+            {/* Root Hash */}
+            <div className="grid grid-cols-[112px_1fr_auto] items-center gap-4 mb-2">
+              <div className="text-sm font-medium text-secondary text-right">Root hash:</div>
+              <input
+                type="text"
+                value={result.rootHash}
+                readOnly
+                className="px-3 py-2.5 bg-secondary border border-primary rounded-lg text-primary font-mono text-sm cursor-default"
+              />
+              <button
+                onClick={() => copyToClipboard(result.rootHash)}
+                className="p-2.5 text-tertiary hover:text-primary hover:bg-tertiary rounded-md transition-colors"
+                title="Copy to clipboard"
+              >
+                <Copy className="w-4 h-4" />
+              </button>
+            </div>
+            <p className="text-sm text-secondary mb-4">
+              {result.fileCount} file{result.fileCount === 1 ? "" : "s"}, {formatBytes(result.totalBytes)}
+            </p>
+
+            {/* Files */}
+            <div className="border border-primary rounded-lg divide-y divide-gray-200 dark:divide-gray-700 max-h-[480px] overflow-auto">
+              {result.files.map((file) => (
+                <div key={file.path} className="grid grid-cols-[1fr_80px_auto] items-center gap-4 px-3 py-2">
+                  <div className="min-w-0">
+                    <div className="text-sm text-primary truncate" title={file.path}>{file.path}</div>
+                    <div className="text-xs text-tertiary font-mono truncate" title={file.hash}>{file.hash}</div>
+                  </div>
+                  <span className="text-xs text-secondary text-right">{formatBytes(file.size)}</span>
+                  <button
+                    onClick={() => copyToClipboard(file.hash)}
+                    className="p-2 text-tertiary hover:text-primary hover:bg-tertiary rounded-md transition-colors"
+                    title="Copy to clipboard"
+                  >
+                    <Copy className="w-4 h-4" />
+                  </button>
+                </div>
+              ))}
+            </div>
+          </>
+        )}
+      </div>
+    </div>
+  );
+}
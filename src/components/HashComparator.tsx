@@ -0,0 +1,194 @@
+import { Fragment, useState } from "react";
+import { CheckCircle, XCircle } from "lucide-react";
+import { invoke } from "@tauri-apps/api/core";
+import { open } from '@tauri-apps/plugin-dialog';
+
+const compareAlgorithms = [
+  { name: "MD5", key: "md5" },
+  { name: "SHA1", key: "sha1" },
+  { name: "SHA224", key: "sha224" },
+  { name: "SHA256", key: "sha256" },
+  { name: "SHA384", key: "sha384" },
+  { name: "SHA512", key: "sha512" },
+  { name: "SHA3-256", key: "sha3-256" },
+  { name: "SHA3-512", key: "sha3-512" },
+  { name: "BLAKE2b-512", key: "blake2b-512" },
+  { name: "BLAKE2s-256", key: "blake2s-256" },
+  { name: "BLAKE3", key: "blake3" },
+];
+
+type CompareMode = "expected" | "file";
+
+interface HashComparison {
+  isMatch: boolean;
+  algorithm: string | null;
+  candidates: string[];
+  expected: string;
+  actual: Record<string, string>;
+}
+
+const selectClass =
+  "px-2 py-2 bg-tertiary border border-primary rounded-lg text-primary text-xs focus:outline-none focus:ring-2 focus:ring-blue-500";
+const buttonClass =
+  "px-3 py-2 text-xs bg-tertiary hover:bg-secondary border border-primary text-primary rounded-lg transition-colors disabled:opacity-50";
+
+const fileNameOf = (path: string) => path.split("/").pop() || path.split("\\").pop() || path;
+
+const pickFile = async () => {
+  const selected = await open({ multiple: false, directory: false });
+  return selected && typeof selected === "string" ? selected : null;
+};
+
+export function HashComparator() {
+  const [mode, setMode] = useState<CompareMode>("expected");
+  const [path, setPath] = useState<string | null>(null);
+  const [otherPath, setOtherPath] = useState<string | null>(null);
+  const [expected, setExpected] = useState("");
+  const [algorithm, setAlgorithm] = useState("");
+  const [result, setResult] = useState<HashComparison | null>(null);
+  const [isComparing, setIsComparing] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+
+  const canCompare = !!path && (mode === "file" ? !!otherPath : expected.trim().length > 0);
+
+  const compare = async () => {
+    if (!canCompare) return;
+    setIsComparing(true);
+    setError(null);
+    try {
+      const comparison = await invoke<HashComparison>("compare_hashes", {
+        path,
+        otherPath: mode === "file" ? otherPath : null,
+        expected: mode === "expected" ? expected : null,
+        algorithm: algorithm || null,
+      });
+      setResult(comparison);
+    } catch (err) {
+      setResult(null);
+      setError(String(err));
+    } finally {
+      setIsComparing(false);
+    }
+  };
+
+  const switchMode = (next: CompareMode) => {
+    setMode(next);
+    setResult(null);
+    setError(null);
+  };
+
+  return (
+    <div className="w-full h-full">
+      <div className="w-full px-4 py-6">
+        {/* Header Controls */}
+        <div className="flex items-center justify-between mb-6">
+          <div className="flex gap-2">
+            <button
+              onClick={() => switchMode("expected")}
+              className={`px-3 py-2 text-xs border border-primary rounded-lg transition-colors ${
+                mode === "expected" ? "bg-blue-600 text-white" : "bg-tertiary hover:bg-secondary text-primary"
+              }`}
+            >
+              File vs. expected hash
+            </button>
+            <button
+              onClick={() => switchMode("file")}
+              className={`px-3 py-2 text-xs border border-primary rounded-lg transition-colors ${
+                mode === "file" ? "bg-blue-600 text-white" : "bg-tertiary hover:bg-secondary text-primary"
+              }`}
+            >
+              File vs. file
+            </button>
+          </div>
+          <select
+            value={algorithm}
+            onChange={(e) => setAlgorithm(e.target.value)}
+            className={selectClass}
+            title="Algorithm"
+          >
+            <option value="">{mode === "file" ? "SHA256 (default)" : "Auto-detect"}</option>
+            {compareAlgorithms.map((algo) => (
+              <option key={algo.key} value={algo.key}>
+                {algo.name}
+              </option>
+            ))}
+          </select>
+        </div>
+
+        {/* Inputs */}
+        <div className="space-y-4 mb-6">
+          <div className="flex items-center gap-3">
+            <button onClick={async () => setPath(await pickFile() ?? path)} className={buttonClass}>
+              Select file...
+            </button>
+            <span className="text-sm text-secondary truncate" title={path ?? undefined}>
+              {path ? fileNameOf(path) : "No file selected"}
+            </span>
+          </div>
+
+          {mode === "file" ? (
+            <div className="flex items-center gap-3">
+              <button onClick={async () => setOtherPath(await pickFile() ?? otherPath)} className={buttonClass}>
+                Select second file...
+              </button>
+              <span className="text-sm text-secondary truncate" title={otherPath ?? undefined}>
+                {otherPath ? fileNameOf(otherPath) : "No file selected"}
+              </span>
+            </div>
+          ) : (
+            <input
+              type="text"
+              value={expected}
+              onChange={(e) => setExpected(e.target.value)}
+              placeholder="Paste the expected hash, e.g. sha256:..., an SRI string or a sha256sum line"
+              className="w-full px-3 py-2.5 bg-tertiary border border-primary rounded-lg text-primary placeholder-tertiary font-mono text-sm focus:outline-none focus:ring-2 focus:ring-blue-500 focus:border-blue-500"
+            />
+          )}
+
+          <button onClick={compare} disabled={!canCompare || isComparing} className={buttonClass}>
+            {isComparing ? "Hashing..." : "Compare"}
+          </button>
+        </div>
+
+        {error && <p className="text-xs text-red-500 mb-4">{error}</p>}
+
+        {result && (
+          <div className="space-y-3">
+            <div className={`flex items-center gap-2 text-sm ${result.isMatch ? "text-green-500" : "text-red-500"}`}>
+              {result.isMatch ? <CheckCircle className="w-4 h-4" /> : <XCircle className="w-4 h-4" />}
+              <span>
+                {result.isMatch
+                  ? `Match (${result.algorithm})`
+                  : result.candidates.length > 1
+                    ? `No match with ${result.candidates.join(", ")}`
+                    : "Hashes do not match"}
+              </span>
+            </div>
+            <div className="grid grid-cols-[112px_1fr] items-center gap-4">
+              <div className="text-sm font-medium text-secondary text-right">Expected:</div>
+              <input
+                type="text"
+                value={result.expected}
+                readOnly
+                className="px-3 py-2.5 bg-secondary border border-primary rounded-lg text-primary font-mono text-sm cursor-default"
+              />
+              {Object.entries(result.actual).map(([name, digest]) => (
+                <Fragment key={name}>
+                  <div className="text-sm font-medium text-secondary text-right">
+                    {name}:
+                  </div>
+                  <input
+                    type="text"
+                    value={digest}
+                    readOnly
+                    className="px-3 py-2.5 bg-secondary border border-primary rounded-lg text-primary font-mono text-sm cursor-default"
+                  />
+                </Fragment>
+              ))}
+            </div>
+          </div>
+        )}
+      </div>
+    </div>
+  );
+}
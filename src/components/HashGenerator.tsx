@@ -1,106 +1,267 @@
-import { useState, useEffect } from "react";
+import { useState, useEffect, useRef } from "react";
 import { Copy } from "lucide-react";
 import { invoke } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
 import { open } from '@tauri-apps/plugin-dialog';
 
-interface HashResults {
-  md5: string;
-  sha1: string;
-  sha224: string;
-  sha256: string;
-  sha384: string;
-  sha512: string;
-  keccak256: string;
+type HashResults = Record<string, string>;
+
+const hashAlgorithms = [
+  { name: "MD2", key: "md2" },
+  { name: "MD4", key: "md4" },
+  { name: "MD5", key: "md5" },
+  { name: "SHA1", key: "sha1" },
+  { name: "SHA224", key: "sha224" },
+  { name: "SHA256", key: "sha256" },
+  { name: "SHA384", key: "sha384" },
+  { name: "SHA512", key: "sha512" },
+  { name: "SHA3-224", key: "sha3-224" },
+  { name: "SHA3-256", key: "sha3-256" },
+  { name: "SHA3-384", key: "sha3-384" },
+  { name: "SHA3-512", key: "sha3-512" },
+  { name: "SHAKE128", key: "shake128" },
+  { name: "SHAKE256", key: "shake256" },
+  { name: "Keccak-256", key: "keccak256" },
+  { name: "BLAKE2b-512", key: "blake2b-512" },
+  { name: "BLAKE2s-256", key: "blake2s-256" },
+  { name: "BLAKE3", key: "blake3" },
+  { name: "XXH32", key: "xxh32" },
+  { name: "XXH64", key: "xxh64" },
+  { name: "XXH3-64", key: "xxh3-64" },
+  { name: "XXH3-128", key: "xxh3-128" },
+];
+
+// Algorithms ticked when the tool opens; the rest are computed only when selected
+const defaultSelection = ["md5", "sha1", "sha256"];
+
+// Extendable-output algorithms; the output length input applies to all of them
+const xofAlgorithms = ["shake128", "shake256", "blake3"];
+
+const outputEncodings = [
+  { name: "Hex", key: "hex" },
+  { name: "Base64", key: "base64" },
+  { name: "Base64URL", key: "base64url" },
+  { name: "Base32", key: "base32" },
+  { name: "Nix Base32", key: "nix32" },
+  { name: "SRI", key: "sri" },
+  { name: "OCI", key: "oci" },
+];
+
+interface CrcVariant {
+  name: string;
+  width: number;
+  poly: string;
+  init: string;
+  reflectIn: boolean;
+  reflectOut: boolean;
+  xorout: string;
+  check: string;
 }
 
+interface HashProgress {
+  jobId: string | null;
+  bytesProcessed: number;
+  totalBytes: number;
+  bytesPerSecond: number;
+  etaSeconds: number | null;
+}
+
+const formatBytes = (bytes: number) => {
+  const units = ["B", "KB", "MB", "GB", "TB"];
+  let value = bytes;
+  let unit = 0;
+  while (value >= 1024 && unit < units.length - 1) {
+    value /= 1024;
+    unit++;
+  }
+  return `${value.toFixed(unit === 0 ? 0 : 1)} ${units[unit]}`;
+};
+
+// Hash a file to lowercase hex while reporting progress through the `hash-progress` event
+const hashFileWithProgress = async (
+  path: string,
+  algorithms: string[],
+  xofBits: number | null,
+  jobId: string,
+  onProgress: (progress: HashProgress) => void
+) => {
+  const unlisten = await listen<HashProgress>("hash-progress", (event) => {
+    if (event.payload.jobId === jobId) {
+      onProgress(event.payload);
+    }
+  });
+  try {
+    return await invoke<Record<string, string>>("hash_file", { path, lowercase: true, algorithms, xofBits, jobId });
+  } finally {
+    unlisten();
+  }
+};
+
 export function HashGenerator() {
   const [input, setInput] = useState("");
-  const [results, setResults] = useState<HashResults>({
-    md5: "",
-    sha1: "",
-    sha224: "",
-    sha256: "",
-    sha384: "",
-    sha512: "",
-    keccak256: "",
-  });
+  // Digests are kept as lowercase hex and only re-encoded for display
+  const [hexResults, setHexResults] = useState<HashResults>({});
+  const [results, setResults] = useState<HashResults>({});
   const [isLowercase, setIsLowercase] = useState(false);
+  const [encoding, setEncoding] = useState("hex");
   const [inputInfo, setInputInfo] = useState("0 bytes (string)");
   const [isProcessingFile, setIsProcessingFile] = useState(false);
   const [isFileMode, setIsFileMode] = useState(false);
   const [currentFilePath, setCurrentFilePath] = useState<string | null>(null);
+  const currentJobId = useRef<string | null>(null);
+  const [partSizeMib, setPartSizeMib] = useState("8");
+  const [s3Etag, setS3Etag] = useState("");
+  const [isComputingEtag, setIsComputingEtag] = useState(false);
+  const [crcVariants, setCrcVariants] = useState<CrcVariant[]>([]);
+  const [crcVariant, setCrcVariant] = useState("crc32");
+  const [crcEnabled, setCrcEnabled] = useState(false);
+  const [selectedAlgorithms, setSelectedAlgorithms] = useState<string[]>(defaultSelection);
+  // Output length in bits for SHAKE and BLAKE3; empty keeps each algorithm's default
+  const [xofBits, setXofBits] = useState("");
+  const xofBitsValue = xofBits.trim() ? Number(xofBits) : null;
+  const hasXofSelected = selectedAlgorithms.some((key) => xofAlgorithms.includes(key));
+  const algorithms = [...selectedAlgorithms, ...(crcEnabled ? [crcVariant] : [])];
+  const algorithmsKey = algorithms.join(",");
+
+  // SRI and OCI only define digests for some SHA-2 variants, so other rows stay empty
+  const placeholderFor = (key: string, fallback: string) => {
+    if (hexResults[key] && !results[key]) {
+      const name = outputEncodings.find((enc) => enc.key === encoding)?.name ?? encoding;
+      return `Not defined for ${name}`;
+    }
+    return fallback;
+  };
+
+  // Drop the XOF digests so they are computed again with the new length
+  const changeXofBits = (value: string) => {
+    setXofBits(value);
+    setHexResults((prev) =>
+      Object.fromEntries(Object.entries(prev).filter(([key]) => !xofAlgorithms.includes(key)))
+    );
+  };
+
+  const toggleAlgorithm = (key: string) => {
+    setSelectedAlgorithms((prev) =>
+      prev.includes(key) ? prev.filter((k) => k !== key) : [...prev, key]
+    );
+  };
 
-  // Generate hashes whenever input or case preference changes (only in text mode)
   useEffect(() => {
-    const generateHashes = async () => {
-      // If we're in file mode, regenerate hashes with new case preference
-      if (isFileMode && currentFilePath) {
-        try {
-          const fileName = currentFilePath.split('/').pop() || currentFilePath.split('\\').pop() || 'Unknown';
-          setInputInfo(`Recalculating hashes for: ${fileName}`);
-          
-          const hashResults = await invoke<Record<string, string>>("hash_file", {
-            path: currentFilePath,
-            lowercase: isLowercase,
-          });
-          
-          setResults({
-            md5: hashResults.md5 || "",
-            sha1: hashResults.sha1 || "",
-            sha224: hashResults.sha224 || "",
-            sha256: hashResults.sha256 || "",
-            sha384: hashResults.sha384 || "",
-            sha512: hashResults.sha512 || "",
-            keccak256: hashResults.keccak256 || "",
-          });
-          
-          setInputInfo(`Hashes calculated for: ${fileName}`);
-        } catch (error) {
-          console.error("Error recalculating file hashes:", error);
-          setInputInfo(`Error: ${error instanceof Error ? error.message : String(error)}`);
-        }
-        return;
-      }
+    invoke<CrcVariant[]>("list_crc_variants")
+      .then(setCrcVariants)
+      .catch((error) => console.error("Error loading CRC variants:", error));
+  }, []);
 
-      if (!input.trim()) {
-        setResults({
-          md5: "",
-          sha1: "",
-          sha224: "",
-          sha256: "",
-          sha384: "",
-          sha512: "",
-          keccak256: "",
-        });
-        setInputInfo("0 bytes (string)");
-        return;
-      }
+  const describeProgress = (fileName: string, progress: HashProgress) => {
+    const percent = progress.totalBytes > 0
+      ? Math.floor((progress.bytesProcessed / progress.totalBytes) * 100)
+      : 100;
+    const eta = progress.etaSeconds !== null ? `, ETA ${Math.ceil(progress.etaSeconds)}s` : "";
+    setInputInfo(
+      `Processing ${fileName}: ${percent}% (${formatBytes(progress.bytesPerSecond)}/s${eta})`
+    );
+  };
 
+  const hashCurrentFile = async (filePath: string, fileName: string, selection: string[]) => {
+    const jobId = crypto.randomUUID();
+    currentJobId.current = jobId;
+    try {
+      return await hashFileWithProgress(filePath, selection, xofBitsValue, jobId, (progress) =>
+        describeProgress(fileName, progress)
+      );
+    } finally {
+      currentJobId.current = null;
+    }
+  };
+
+  const cancelFileHash = async () => {
+    if (currentJobId.current) {
+      await invoke<boolean>("cancel_hash_job", { jobId: currentJobId.current });
+    }
+  };
+
+  // Generate hashes whenever the text input, the algorithm selection or the output length changes
+  useEffect(() => {
+    if (isFileMode) {
+      return;
+    }
+    if (!input.trim()) {
+      setHexResults({});
+      setInputInfo("0 bytes (string)");
+      return;
+    }
+
+    const byteLength = new TextEncoder().encode(input).length;
+    setInputInfo(`${byteLength} bytes (string)`);
+    if (algorithms.length === 0) {
+      setHexResults({});
+      return;
+    }
+
+    invoke<Record<string, string>>("hash_string", { input, lowercase: true, algorithms, xofBits: xofBitsValue })
+      .then(setHexResults)
+      .catch((error) => {
+        console.error("Error generating hashes:", error);
+        setInputInfo(`Error: ${error instanceof Error ? error.message : String(error)}`);
+      });
+  }, [input, isFileMode, algorithmsKey, xofBits]);
+
+  // In file mode, only read the file again for algorithms that were just selected
+  useEffect(() => {
+    if (!isFileMode || !currentFilePath || isProcessingFile) {
+      return;
+    }
+    const missing = algorithms.filter((key) => !(key in hexResults));
+    if (missing.length === 0) {
+      return;
+    }
+
+    const fileName = currentFilePath.split('/').pop() || currentFilePath.split('\\').pop() || 'Unknown';
+    const hashMissing = async () => {
       try {
-        const byteLength = new TextEncoder().encode(input).length;
-        setInputInfo(`${byteLength} bytes (string)`);
-        
-        const hashResults = await invoke<Record<string, string>>("hash_string", {
-          input,
-          lowercase: isLowercase,
-        });
-        
-        setResults({
-          md5: hashResults.md5 || "",
-          sha1: hashResults.sha1 || "",
-          sha224: hashResults.sha224 || "",
-          sha256: hashResults.sha256 || "",
-          sha384: hashResults.sha384 || "",
-          sha512: hashResults.sha512 || "",
-          keccak256: hashResults.keccak256 || "",
-        });
+        setIsProcessingFile(true);
+        setInputInfo(`Calculating ${missing.length} more hash(es) for: ${fileName}`);
+        const hashResults = await hashCurrentFile(currentFilePath, fileName, missing);
+        setHexResults((prev) => ({ ...prev, ...hashResults }));
+        setInputInfo(`Hashes calculated for: ${fileName}`);
       } catch (error) {
-        console.error("Error generating hashes:", error);
+        console.error("Error calculating file hashes:", error);
+        setInputInfo(`Error: ${error instanceof Error ? error.message : String(error)}`);
+      } finally {
+        setIsProcessingFile(false);
       }
     };
 
-    generateHashes();
-  }, [input, isLowercase, isFileMode, currentFilePath]);
+    hashMissing();
+  }, [isFileMode, currentFilePath, algorithmsKey, xofBits]);
+
+  // Changing the encoding or case re-encodes the existing digests
+  useEffect(() => {
+    if (Object.keys(hexResults).length === 0) {
+      setResults({});
+      return;
+    }
+    invoke<Record<string, string>>("encode_digests", { digests: hexResults, lowercase: isLowercase, encoding })
+      .then(setResults)
+      .catch((error) => console.error("Error encoding hashes:", error));
+  }, [hexResults, encoding, isLowercase]);
+
+  // S3 multipart ETags depend on the part size used for the upload
+  const computeS3Etag = async () => {
+    if (!currentFilePath) {
+      return;
+    }
+    setIsComputingEtag(true);
+    try {
+      const partSize = Math.round(Number(partSizeMib) * 1024 * 1024);
+      setS3Etag(await invoke<string>("s3_etag", { path: currentFilePath, partSize }));
+    } catch (error) {
+      setS3Etag("");
+      setInputInfo(`Error: ${error instanceof Error ? error.message : String(error)}`);
+    } finally {
+      setIsComputingEtag(false);
+    }
+  };
 
   const copyToClipboard = async (text: string) => {
     try {
@@ -132,15 +293,8 @@ export function HashGenerator() {
     setInput("");
     setIsFileMode(false);
     setCurrentFilePath(null);
-    setResults({
-      md5: "",
-      sha1: "",
-      sha224: "",
-      sha256: "",
-      sha384: "",
-      sha512: "",
-      keccak256: "",
-    });
+    setHexResults({});
+    setS3Etag("");
     setInputInfo("0 bytes (string)");
   };
 
@@ -168,20 +322,10 @@ export function HashGenerator() {
         setInputInfo(`Processing file: ${fileName}`);
         
         // Calculate file hashes
-        const hashResults = await invoke<Record<string, string>>("hash_file", {
-          path: filePath,
-          lowercase: isLowercase,
-        });
+        const hashResults = await hashCurrentFile(filePath, fileName, algorithms);
         
-        setResults({
-          md5: hashResults.md5 || "",
-          sha1: hashResults.sha1 || "",
-          sha224: hashResults.sha224 || "",
-          sha256: hashResults.sha256 || "",
-          sha384: hashResults.sha384 || "",
-          sha512: hashResults.sha512 || "",
-          keccak256: hashResults.keccak256 || "",
-        });
+        setHexResults(hashResults);
+        setS3Etag("");
         
         // Update info with hash completion
         setInputInfo(`Hashes calculated for: ${fileName}`);
@@ -201,15 +345,6 @@ export function HashGenerator() {
     }
   };
 
-  const hashAlgorithms = [
-    { name: "MD5", key: "md5" as keyof HashResults },
-    { name: "SHA1", key: "sha1" as keyof HashResults },
-    { name: "SHA224", key: "sha224" as keyof HashResults },
-    { name: "SHA256", key: "sha256" as keyof HashResults },
-    { name: "SHA384", key: "sha384" as keyof HashResults },
-    { name: "SHA512", key: "sha512" as keyof HashResults },
-    { name: "Keccak-256", key: "keccak256" as keyof HashResults },
-  ];
 
   return (
     <div className="w-full h-full">
@@ -232,15 +367,14 @@ export function HashGenerator() {
                 Sample
               </button>
               <button
-                onClick={handleFileLoad}
-                disabled={isProcessingFile}
+                onClick={isProcessingFile ? cancelFileHash : handleFileLoad}
                 className={`px-3 py-2 text-xs border border-primary rounded-lg transition-colors ${
                   isProcessingFile 
-                    ? 'bg-blue-600 text-white cursor-not-allowed' 
+                    ? 'bg-blue-600 hover:bg-blue-700 text-white' 
                     : 'bg-tertiary hover:bg-secondary text-primary'
                 }`}
               >
-                {isProcessingFile ? 'Processing...' : 'Load file...'}
+                {isProcessingFile ? 'Cancel' : 'Load file...'}
               </button>
               <button
                 onClick={clearAll}
@@ -253,10 +387,35 @@ export function HashGenerator() {
           
           <div className="flex items-center gap-3">
             <span className="text-sm text-secondary">{inputInfo}</span>
+            {hasXofSelected && (
+              <input
+                type="number"
+                min={8}
+                step={8}
+                value={xofBits}
+                onChange={(e) => changeXofBits(e.target.value)}
+                placeholder="XOF bits"
+                className="w-24 px-2 py-2 bg-tertiary border border-primary rounded-lg text-primary placeholder-tertiary text-xs focus:outline-none focus:ring-2 focus:ring-blue-500"
+                title="Output length in bits for SHAKE128, SHAKE256 and BLAKE3 (defaults 256, 512 and 256)"
+              />
+            )}
+            <select
+              value={encoding}
+              onChange={(e) => setEncoding(e.target.value)}
+              className="px-2 py-2 bg-tertiary border border-primary rounded-lg text-primary text-xs focus:outline-none focus:ring-2 focus:ring-blue-500"
+              title="Output encoding"
+            >
+              {outputEncodings.map((enc) => (
+                <option key={enc.key} value={enc.key}>
+                  {enc.name}
+                </option>
+              ))}
+            </select>
             <label className="flex items-center gap-2 cursor-pointer">
               <input
                 type="checkbox"
                 checked={isLowercase}
+                disabled={encoding !== "hex"}
                 onChange={(e) => setIsLowercase(e.target.checked)}
                 className="w-4 h-4 text-blue-600 bg-secondary border-primary rounded focus:ring-blue-500"
               />
@@ -282,28 +441,125 @@ export function HashGenerator() {
 
         {/* Hash Results */}
         <div className="space-y-3">
-          {hashAlgorithms.map((algo) => (
-            <div key={algo.key} className="grid grid-cols-[112px_1fr_auto] items-center gap-4">
-              <div className="text-sm font-medium text-secondary text-right">
-                {algo.name}:
+          {hashAlgorithms.map((algo) => {
+            const selected = selectedAlgorithms.includes(algo.key);
+            const value = selected ? results[algo.key] || "" : "";
+            return (
+              <div key={algo.key} className="grid grid-cols-[16px_112px_1fr_auto] items-center gap-4">
+                <input
+                  type="checkbox"
+                  checked={selected}
+                  onChange={() => toggleAlgorithm(algo.key)}
+                  className="w-4 h-4 text-blue-600 bg-secondary border-primary rounded focus:ring-blue-500"
+                  title={selected ? `Skip ${algo.name}` : `Compute ${algo.name}`}
+                />
+                <div
+                  className={`text-sm font-medium text-right ${selected ? "text-secondary" : "text-tertiary"}`}
+                  title={algo.key === "keccak256" ? "Original Keccak (Ethereum), not FIPS 202 SHA3-256" : undefined}
+                >
+                  {algo.name}:
+                </div>
+                <input
+                  type="text"
+                  value={value}
+                  readOnly
+                  className="px-3 py-2.5 bg-secondary border border-primary rounded-lg text-primary font-mono text-sm cursor-default"
+                  placeholder={selected ? placeholderFor(algo.key, "Hash will appear here...") : "Not selected"}
+                />
+                <button
+                  onClick={() => copyToClipboard(value)}
+                  className="p-2.5 text-tertiary hover:text-primary hover:bg-tertiary rounded-md transition-colors"
+                  disabled={!value}
+                  title="Copy to clipboard"
+                >
+                  <Copy className="w-4 h-4" />
+                </button>
+              </div>
+            );
+          })}
+          <div className="grid grid-cols-[16px_112px_1fr_auto] items-center gap-4">
+            <input
+              type="checkbox"
+              checked={crcEnabled}
+              onChange={(e) => setCrcEnabled(e.target.checked)}
+              className="w-4 h-4 text-blue-600 bg-secondary border-primary rounded focus:ring-blue-500"
+              title={crcEnabled ? "Skip the CRC" : "Compute the CRC"}
+            />
+            <select
+              value={crcVariant}
+              onChange={(e) => setCrcVariant(e.target.value)}
+              className="px-2 py-2 bg-tertiary border border-primary rounded-lg text-primary text-xs focus:outline-none focus:ring-2 focus:ring-blue-500"
+              title={(() => {
+                const variant = crcVariants.find((v) => v.name === crcVariant);
+                return variant
+                  ? `poly=${variant.poly} init=${variant.init} refin=${variant.reflectIn} refout=${variant.reflectOut} xorout=${variant.xorout}`
+                  : undefined;
+              })()}
+            >
+              {(crcVariants.length > 0 ? crcVariants.map((v) => v.name) : [crcVariant]).map((name) => (
+                <option key={name} value={name}>
+                  {name.toUpperCase()}
+                </option>
+              ))}
+            </select>
+            <input
+              type="text"
+              value={crcEnabled ? results[crcVariant] || "" : ""}
+              readOnly
+              className="px-3 py-2.5 bg-secondary border border-primary rounded-lg text-primary font-mono text-sm cursor-default"
+              placeholder={crcEnabled ? placeholderFor(crcVariant, "Checksum will appear here...") : "Not selected"}
+            />
+            <button
+              onClick={() => copyToClipboard(results[crcVariant] || "")}
+              className="p-2.5 text-tertiary hover:text-primary hover:bg-tertiary rounded-md transition-colors"
+              disabled={!crcEnabled || !results[crcVariant]}
+              title="Copy to clipboard"
+            >
+              <Copy className="w-4 h-4" />
+            </button>
+          </div>
+          {isFileMode && currentFilePath && (
+            <div className="grid grid-cols-[16px_112px_1fr_auto] items-center gap-4">
+              <span />
+              <div className="text-sm font-medium text-secondary text-right">S3 ETag:</div>
+              <div className="flex items-center gap-2">
+                <input
+                  type="number"
+                  min={1}
+                  value={partSizeMib}
+                  onChange={(e) => {
+                    setPartSizeMib(e.target.value);
+                    setS3Etag("");
+                  }}
+                  className="w-20 px-2 py-2 bg-tertiary border border-primary rounded-lg text-primary text-xs focus:outline-none focus:ring-2 focus:ring-blue-500"
+                  title="Part size in MiB used for the multipart upload"
+                />
+                <span className="text-xs text-secondary">MiB</span>
+                <button
+                  onClick={computeS3Etag}
+                  disabled={isComputingEtag || isProcessingFile || !(Number(partSizeMib) > 0)}
+                  className="px-3 py-2 text-xs bg-tertiary hover:bg-secondary border border-primary text-primary rounded-lg transition-colors disabled:opacity-50"
+                >
+                  {isComputingEtag ? "Computing..." : "Compute"}
+                </button>
+                <input
+                  type="text"
+                  value={s3Etag}
+                  readOnly
+                  className="flex-1 px-3 py-2.5 bg-secondary border border-primary rounded-lg text-primary font-mono text-sm cursor-default"
+                  placeholder="ETag will appear here..."
+                />
               </div>
-              <input
-                type="text"
-                value={results[algo.key]}
-                readOnly
-                className="px-3 py-2.5 bg-secondary border border-primary rounded-lg text-primary font-mono text-sm cursor-default"
-                placeholder="Hash will appear here..."
-              />
               <button
-                onClick={() => copyToClipboard(results[algo.key])}
+                onClick={() => copyToClipboard(s3Etag)}
                 className="p-2.5 text-tertiary hover:text-primary hover:bg-tertiary rounded-md transition-colors"
-                disabled={!results[algo.key]}
+                disabled={!s3Etag}
                 title="Copy to clipboard"
               >
                 <Copy className="w-4 h-4" />
               </button>
             </div>
-          ))}
+          )}
         </div>
       </div>
     </div>
@@ -0,0 +1,272 @@
+import { useState, useEffect } from "react";
+import { Copy, CheckCircle, XCircle } from "lucide-react";
+import { invoke } from "@tauri-apps/api/core";
+
+const hmacAlgorithms = [
+  { name: "HMAC-MD5", key: "md5" },
+  { name: "HMAC-SHA1", key: "sha1" },
+  { name: "HMAC-SHA224", key: "sha224" },
+  { name: "HMAC-SHA256", key: "sha256" },
+  { name: "HMAC-SHA384", key: "sha384" },
+  { name: "HMAC-SHA512", key: "sha512" },
+  { name: "HMAC-SHA3-224", key: "sha3-224" },
+  { name: "HMAC-SHA3-256", key: "sha3-256" },
+  { name: "HMAC-SHA3-384", key: "sha3-384" },
+  { name: "HMAC-SHA3-512", key: "sha3-512" },
+];
+
+const keyEncodings = [
+  { name: "UTF-8", key: "utf8" },
+  { name: "Hex", key: "hex" },
+  { name: "Base64", key: "base64" },
+];
+
+const outputEncodings = [
+  { name: "Hex", key: "hex" },
+  { name: "Base64", key: "base64" },
+];
+
+// Webhook formats; Stripe and Slack sign a timestamp along with the body
+const signatureFormats = [
+  { name: "Raw", key: "" },
+  { name: "GitHub", key: "github" },
+  { name: "Stripe", key: "stripe" },
+  { name: "Slack", key: "slack" },
+];
+
+interface HmacVerifyResult {
+  isValid: boolean;
+  computed: string;
+}
+
+const selectClass =
+  "px-2 py-2 bg-tertiary border border-primary rounded-lg text-primary text-xs focus:outline-none focus:ring-2 focus:ring-blue-500";
+const inputClass =
+  "w-full px-3 py-2.5 bg-tertiary border border-primary rounded-lg text-primary placeholder-tertiary font-mono text-sm focus:outline-none focus:ring-2 focus:ring-blue-500 focus:border-blue-500";
+
+export function HmacTool() {
+  const [message, setMessage] = useState("");
+  const [key, setKey] = useState("");
+  const [algorithm, setAlgorithm] = useState("sha256");
+  const [keyEncoding, setKeyEncoding] = useState("utf8");
+  const [outputEncoding, setOutputEncoding] = useState("hex");
+  const [signatureFormat, setSignatureFormat] = useState("");
+  const [timestamp, setTimestamp] = useState("");
+  const [expected, setExpected] = useState("");
+  const [signature, setSignature] = useState("");
+  const [error, setError] = useState<string | null>(null);
+  const [verifyResult, setVerifyResult] = useState<HmacVerifyResult | null>(null);
+  const [verifyError, setVerifyError] = useState<string | null>(null);
+
+  const needsTimestamp = signatureFormat === "stripe" || signatureFormat === "slack";
+  const options = {
+    algorithm,
+    keyEncoding,
+    outputEncoding,
+    signatureFormat: signatureFormat || null,
+    timestamp: needsTimestamp && timestamp.trim() ? timestamp : null,
+  };
+  const optionsKey = JSON.stringify(options);
+
+  useEffect(() => {
+    if (!key) {
+      setSignature("");
+      setError(null);
+      return;
+    }
+    // Stripe and Slack signatures cannot be produced until a timestamp is given
+    if (needsTimestamp && !timestamp.trim()) {
+      setSignature("");
+      setError(null);
+      return;
+    }
+    invoke<string>("generate_hmac", { message, key, options })
+      .then((value) => {
+        setSignature(value);
+        setError(null);
+      })
+      .catch((err) => {
+        setSignature("");
+        setError(String(err));
+      });
+  }, [message, key, optionsKey]);
+
+  useEffect(() => {
+    if (!key || !expected.trim()) {
+      setVerifyResult(null);
+      setVerifyError(null);
+      return;
+    }
+    invoke<HmacVerifyResult>("verify_hmac", { message, key, expected, options })
+      .then((result) => {
+        setVerifyResult(result);
+        setVerifyError(null);
+      })
+      .catch((err) => {
+        setVerifyResult(null);
+        setVerifyError(String(err));
+      });
+  }, [message, key, expected, optionsKey]);
+
+  const copyToClipboard = async (text: string) => {
+    try {
+      await navigator.clipboard.writeText(text);
+    } catch (error) {
+      console.error("Failed to copy to clipboard:", error);
+    }
+  };
+
+  const clearAll = () => {
+    setMessage("");
+    setKey("");
+    setTimestamp("");
+    setExpected("");
+  };
+
+  return (
+    <div className="w-full h-full">
+      <div className="w-full px-4 py-6">
+        {/* Header Controls */}
+        <div className="flex items-center justify-between mb-6">
+          <div className="flex items-center gap-3">
+            <select
+              value={algorithm}
+              onChange={(e) => setAlgorithm(e.target.value)}
+              className={selectClass}
+              title="Algorithm"
+            >
+              {hmacAlgorithms.map((algo) => (
+                <option key={algo.key} value={algo.key}>
+                  {algo.name}
+                </option>
+              ))}
+            </select>
+            <select
+              value={signatureFormat}
+              onChange={(e) => setSignatureFormat(e.target.value)}
+              className={selectClass}
+              title="Signature format"
+            >
+              {signatureFormats.map((format) => (
+                <option key={format.key} value={format.key}>
+                  {format.name}
+                </option>
+              ))}
+            </select>
+            <select
+              value={outputEncoding}
+              onChange={(e) => setOutputEncoding(e.target.value)}
+              disabled={signatureFormat !== ""}
+              className={selectClass}
+              title="Output encoding"
+            >
+              {outputEncodings.map((enc) => (
+                <option key={enc.key} value={enc.key}>
+                  {enc.name}
+                </option>
+              ))}
+            </select>
+          </div>
+          <button
+            onClick={clearAll}
+            className="px-3 py-2 text-xs bg-tertiary hover:bg-secondary border border-primary text-primary rounded-lg transition-colors"
+          >
+            Clear
+          </button>
+        </div>
+
+        {/* Key */}
+        <div className="mb-4">
+          <div className="flex items-center justify-between mb-2">
+            <span className="text-sm text-secondary">Secret key</span>
+            <select
+              value={keyEncoding}
+              onChange={(e) => setKeyEncoding(e.target.value)}
+              className={selectClass}
+              title="Key encoding"
+            >
+              {keyEncodings.map((enc) => (
+                <option key={enc.key} value={enc.key}>
+                  {enc.name}
+                </option>
+              ))}
+            </select>
+          </div>
+          <input
+            type="text"
+            value={key}
+            onChange={(e) => setKey(e.target.value)}
+            placeholder="Enter the shared secret..."
+            className={inputClass}
+          />
+        </div>
+
+        {needsTimestamp && (
+          <div className="mb-4">
+            <span className="block text-sm text-secondary mb-2">Timestamp</span>
+            <input
+              type="text"
+              value={timestamp}
+              onChange={(e) => setTimestamp(e.target.value)}
+              placeholder="Unix timestamp sent with the request"
+              className={inputClass}
+            />
+          </div>
+        )}
+
+        {/* Message */}
+        <div className="mb-6">
+          <span className="block text-sm text-secondary mb-2">Message</span>
+          <textarea
+            value={message}
+            onChange={(e) => setMessage(e.target.value)}
+            placeholder="Enter the message or raw request body..."
+            className="w-full h-32 px-4 py-3 bg-tertiary border border-primary rounded-lg text-primary placeholder-tertiary focus:outline-none focus:ring-2 focus:ring-blue-500 focus:border-blue-500 font-mono text-sm transition-colors resize-none"
+          />
+        </div>
+
+        {/* Signature */}
+        <div className="mb-6">
+          <span className="block text-sm text-secondary mb-2">Signature</span>
+          <div className="flex items-center gap-4">
+            <input
+              type="text"
+              value={signature}
+              readOnly
+              className="flex-1 px-3 py-2.5 bg-secondary border border-primary rounded-lg text-primary font-mono text-sm cursor-default"
+              placeholder={needsTimestamp && !timestamp.trim() ? "Enter a timestamp to sign..." : "Signature will appear here..."}
+            />
+            <button
+              onClick={() => copyToClipboard(signature)}
+              className="p-2.5 text-tertiary hover:text-primary hover:bg-tertiary rounded-md transition-colors"
+              disabled={!signature}
+              title="Copy to clipboard"
+            >
+              <Copy className="w-4 h-4" />
+            </button>
+          </div>
+          {error && <p className="mt-2 text-xs text-red-500">{error}</p>}
+        </div>
+
+        {/* Verify */}
+        <div>
+          <span className="block text-sm text-secondary mb-2">Verify against</span>
+          <input
+            type="text"
+            value={expected}
+            onChange={(e) => setExpected(e.target.value)}
+            placeholder="Paste the received signature or header value..."
+            className={inputClass}
+          />
+          {verifyResult && (
+            <div className={`mt-2 flex items-center gap-2 text-sm ${verifyResult.isValid ? "text-green-500" : "text-red-500"}`}>
+              {verifyResult.isValid ? <CheckCircle className="w-4 h-4" /> : <XCircle className="w-4 h-4" />}
+              <span>{verifyResult.isValid ? "Signature is valid" : "Signature does not match"}</span>
+            </div>
+          )}
+          {verifyError && <p className="mt-2 text-xs text-red-500">{verifyError}</p>}
+        </div>
+      </div>
+    </div>
+  );
+}
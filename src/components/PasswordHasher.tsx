@@ -0,0 +1,267 @@
+import { useState } from "react";
+import { Copy, CheckCircle, XCircle } from "lucide-react";
+import { invoke } from "@tauri-apps/api/core";
+
+const passwordAlgorithms = [
+  { name: "Argon2id", key: "argon2id" },
+  { name: "Argon2i", key: "argon2i" },
+  { name: "Argon2d", key: "argon2d" },
+  { name: "bcrypt", key: "bcrypt" },
+  { name: "scrypt", key: "scrypt" },
+  { name: "PBKDF2-SHA256", key: "pbkdf2-sha256" },
+  { name: "PBKDF2-SHA512", key: "pbkdf2-sha512" },
+];
+
+type CostField = "cost" | "memoryKib" | "iterations" | "parallelism" | "logN" | "blockSize" | "outputLen";
+
+// Cost inputs shown for each algorithm family; an empty input keeps the backend default
+const costFields: Record<string, { field: CostField; label: string; placeholder: string }[]> = {
+  argon2: [
+    { field: "memoryKib", label: "Memory (KiB)", placeholder: "19456" },
+    { field: "iterations", label: "Iterations", placeholder: "2" },
+    { field: "parallelism", label: "Parallelism", placeholder: "1" },
+    { field: "outputLen", label: "Output bytes", placeholder: "32" },
+  ],
+  bcrypt: [{ field: "cost", label: "Cost", placeholder: "12" }],
+  scrypt: [
+    { field: "logN", label: "log2(N)", placeholder: "17" },
+    { field: "blockSize", label: "Block size (r)", placeholder: "8" },
+    { field: "parallelism", label: "Parallelism (p)", placeholder: "1" },
+    { field: "outputLen", label: "Output bytes", placeholder: "32" },
+  ],
+  pbkdf2: [
+    { field: "iterations", label: "Rounds", placeholder: "600000 / 210000" },
+    { field: "outputLen", label: "Output bytes", placeholder: "32 / 64" },
+  ],
+};
+
+interface PasswordHashParams {
+  algorithm: string;
+  version: string | null;
+  cost: number | null;
+  memoryKib: number | null;
+  iterations: number | null;
+  parallelism: number | null;
+  logN: number | null;
+  blockSize: number | null;
+  outputLen: number | null;
+  salt: string | null;
+}
+
+interface PasswordHashResult {
+  hash: string;
+  params: PasswordHashParams;
+}
+
+interface PasswordVerifyResult {
+  isValid: boolean;
+  params: PasswordHashParams | null;
+  error: string | null;
+}
+
+const paramLabels: [keyof PasswordHashParams, string][] = [
+  ["algorithm", "Algorithm"],
+  ["version", "Version"],
+  ["cost", "Cost"],
+  ["memoryKib", "Memory (KiB)"],
+  ["iterations", "Iterations"],
+  ["parallelism", "Parallelism"],
+  ["logN", "log2(N)"],
+  ["blockSize", "Block size"],
+  ["outputLen", "Output bytes"],
+  ["salt", "Salt"],
+];
+
+const familyOf = (algorithm: string) => {
+  if (algorithm.startsWith("argon2")) return "argon2";
+  if (algorithm.startsWith("pbkdf2")) return "pbkdf2";
+  return algorithm;
+};
+
+const inputClass =
+  "w-full px-3 py-2.5 bg-tertiary border border-primary rounded-lg text-primary placeholder-tertiary font-mono text-sm focus:outline-none focus:ring-2 focus:ring-blue-500 focus:border-blue-500";
+const buttonClass =
+  "px-3 py-2 text-xs bg-tertiary hover:bg-secondary border border-primary text-primary rounded-lg transition-colors disabled:opacity-50";
+
+function ParamsTable({ params }: { params: PasswordHashParams }) {
+  const rows = paramLabels.filter(([key]) => params[key] !== null && params[key] !== undefined);
+  return (
+    <div className="grid grid-cols-[112px_1fr] gap-x-4 gap-y-1 text-sm">
+      {rows.map(([key, label]) => (
+        <div key={key} className="contents">
+          <span className="text-secondary text-right">{label}:</span>
+          <span className="text-primary font-mono break-all">{String(params[key])}</span>
+        </div>
+      ))}
+    </div>
+  );
+}
+
+export function PasswordHasher() {
+  const [password, setPassword] = useState("");
+  const [algorithm, setAlgorithm] = useState("argon2id");
+  const [costs, setCosts] = useState<Partial<Record<CostField, string>>>({});
+  const [result, setResult] = useState<PasswordHashResult | null>(null);
+  const [isHashing, setIsHashing] = useState(false);
+  const [hashError, setHashError] = useState<string | null>(null);
+
+  const [verifyPassword, setVerifyPassword] = useState("");
+  const [verifyHash, setVerifyHash] = useState("");
+  const [verifyResult, setVerifyResult] = useState<PasswordVerifyResult | null>(null);
+  const [isVerifying, setIsVerifying] = useState(false);
+
+  const fields = costFields[familyOf(algorithm)] ?? [];
+
+  const hashPassword = async () => {
+    setIsHashing(true);
+    setHashError(null);
+    try {
+      const options: Record<string, string | number | null> = { algorithm };
+      for (const { field } of fields) {
+        const value = costs[field]?.trim();
+        options[field] = value ? Number(value) : null;
+      }
+      setResult(await invoke<PasswordHashResult>("hash_password", { password, options }));
+    } catch (error) {
+      setResult(null);
+      setHashError(String(error));
+    } finally {
+      setIsHashing(false);
+    }
+  };
+
+  const checkPassword = async () => {
+    setIsVerifying(true);
+    try {
+      setVerifyResult(
+        await invoke<PasswordVerifyResult>("verify_password", { password: verifyPassword, hash: verifyHash })
+      );
+    } catch (error) {
+      setVerifyResult({ isValid: false, params: null, error: String(error) });
+    } finally {
+      setIsVerifying(false);
+    }
+  };
+
+  const copyToClipboard = async (text: string) => {
+    try {
+      await navigator.clipboard.writeText(text);
+    } catch (error) {
+      console.error("Failed to copy to clipboard:", error);
+    }
+  };
+
+  return (
+    <div className="w-full h-full">
+      <div className="w-full px-4 py-6 space-y-8">
+        {/* Hash */}
+        <section>
+          <div className="flex items-center justify-between mb-4">
+            <span className="text-sm font-medium text-primary">Hash a password</span>
+            <select
+              value={algorithm}
+              onChange={(e) => setAlgorithm(e.target.value)}
+              className="px-2 py-2 bg-tertiary border border-primary rounded-lg text-primary text-xs focus:outline-none focus:ring-2 focus:ring-blue-500"
+              title="Algorithm"
+            >
+              {passwordAlgorithms.map((algo) => (
+                <option key={algo.key} value={algo.key}>
+                  {algo.name}
+                </option>
+              ))}
+            </select>
+          </div>
+
+          <input
+            type="text"
+            value={password}
+            onChange={(e) => setPassword(e.target.value)}
+            placeholder="Enter a password..."
+            className={`${inputClass} mb-4`}
+          />
+
+          <div className="grid grid-cols-4 gap-4 mb-4">
+            {fields.map(({ field, label, placeholder }) => (
+              <div key={field}>
+                <span className="block text-xs text-secondary mb-1">{label}</span>
+                <input
+                  type="number"
+                  min={0}
+                  value={costs[field] ?? ""}
+                  onChange={(e) => setCosts((prev) => ({ ...prev, [field]: e.target.value }))}
+                  placeholder={placeholder}
+                  className={inputClass}
+                />
+              </div>
+            ))}
+          </div>
+
+          <button onClick={hashPassword} disabled={isHashing} className={`${buttonClass} mb-4`}>
+            {isHashing ? "Hashing..." : "Hash"}
+          </button>
+
+          {hashError && <p className="text-xs text-red-500 mb-4">{hashError}</p>}
+
+          {result && (
+            <div className="space-y-3">
+              <div className="flex items-center gap-4">
+                <input
+                  type="text"
+                  value={result.hash}
+                  readOnly
+                  className="flex-1 px-3 py-2.5 bg-secondary border border-primary rounded-lg text-primary font-mono text-sm cursor-default"
+                />
+                <button
+                  onClick={() => copyToClipboard(result.hash)}
+                  className="p-2.5 text-tertiary hover:text-primary hover:bg-tertiary rounded-md transition-colors"
+                  title="Copy to clipboard"
+                >
+                  <Copy className="w-4 h-4" />
+                </button>
+              </div>
+              <ParamsTable params={result.params} />
+            </div>
+          )}
+        </section>
+
+        {/* Verify */}
+        <section>
+          <span className="block text-sm font-medium text-primary mb-4">Verify a password</span>
+          <div className="space-y-3 mb-4">
+            <input
+              type="text"
+              value={verifyPassword}
+              onChange={(e) => setVerifyPassword(e.target.value)}
+              placeholder="Password"
+              className={inputClass}
+            />
+            <input
+              type="text"
+              value={verifyHash}
+              onChange={(e) => setVerifyHash(e.target.value)}
+              placeholder="$argon2id$..., $scrypt$..., $pbkdf2-sha256$... or $2b$..."
+              className={inputClass}
+            />
+          </div>
+          <button
+            onClick={checkPassword}
+            disabled={!verifyHash.trim() || isVerifying}
+            className={`${buttonClass} mb-4`}
+          >
+            {isVerifying ? "Verifying..." : "Verify"}
+          </button>
+
+          {verifyResult && (
+            <div className="space-y-3">
+              <div className={`flex items-center gap-2 text-sm ${verifyResult.isValid ? "text-green-500" : "text-red-500"}`}>
+                {verifyResult.isValid ? <CheckCircle className="w-4 h-4" /> : <XCircle className="w-4 h-4" />}
+                <span>{verifyResult.isValid ? "Password matches" : verifyResult.error || "Password does not match"}</span>
+              </div>
+              {verifyResult.params && <ParamsTable params={verifyResult.params} />}
+            </div>
+          )}
+        </section>
+      </div>
+    </div>
+  );
+}
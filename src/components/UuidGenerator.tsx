@@ -2,6 +2,107 @@ import React, { useState, useCallback, useRef } from 'react';
 import { Copy, Clipboard, FileText, Trash2, Wand2 } from 'lucide-react';
 import { invoke } from '@tauri-apps/api/core';
 
+interface UuidField {
+  name: string;
+  bitOffset: number;
+  bitWidth: number;
+  bits: string;
+  hex: string;
+}
+
+interface V8Field {
+  name: string;
+  width: number;
+  source: 'timestamp' | 'counter' | 'constant' | 'random';
+  value?: string;
+}
+
+interface CustomField {
+  name: string;
+  source: string;
+  value: string;
+  hex: string;
+  timestamp: string | null;
+}
+
+const defaultV8Template: V8Field[] = [
+  { name: 'timestamp', width: 48, source: 'timestamp' },
+  { name: 'shard', width: 10, source: 'constant', value: '1' },
+  { name: 'sequence', width: 12, source: 'counter' },
+  { name: 'random', width: 52, source: 'random' },
+];
+
+interface ParsedId {
+  format: string;
+  timestamp: string | null;
+  unixMillis: number | null;
+  components: { name: string; value: string }[];
+}
+
+const idFormats: Record<string, string> = {
+  ulid: 'ULID',
+  ksuid: 'KSUID',
+  nanoid: 'NanoID',
+  cuid2: 'CUID2',
+  typeid: 'TypeID',
+  snowflake: 'Snowflake',
+};
+
+// Guess which non-UUID format an ID is in from its shape
+const detectIdFormat = (id: string): string | null => {
+  if (/^\d+$/.test(id)) return 'snowflake';
+  if (id.includes('_')) return 'typeid';
+  if (id.length === 26) return 'ulid';
+  if (id.length === 27) return 'ksuid';
+  return null;
+};
+
+interface UuidConversions {
+  inputFormat: string;
+  hyphenated: string;
+  simple: string;
+  braced: string;
+  urn: string;
+  base64: string;
+  base64Url: string;
+  base32: string;
+  crockford: string;
+  decimal: string;
+  bytes: string;
+  javaBytes: string;
+  guidBytes: string;
+  mysqlSwapHex: string;
+}
+
+const conversionLabels: [keyof UuidConversions, string][] = [
+  ['hyphenated', 'Hyphenated'],
+  ['simple', 'Simple'],
+  ['braced', 'Braced'],
+  ['urn', 'URN'],
+  ['base64', 'Base64'],
+  ['base64Url', 'Base64url'],
+  ['base32', 'Base32'],
+  ['crockford', 'Crockford / ULID'],
+  ['decimal', 'Decimal'],
+  ['bytes', 'Bytes'],
+  ['javaBytes', 'Java byte[]'],
+  ['guidBytes', '.NET GUID bytes'],
+  ['mysqlSwapHex', 'MySQL UUID_TO_BIN(swap)'],
+];
+
+interface UuidListAnalysis {
+  totalLines: number;
+  validCount: number;
+  uniqueCount: number;
+  invalidLines: { line: number; text: string; error: string }[];
+  duplicates: { uuid: string; lines: number[] }[];
+  versions: { version: string; count: number }[];
+  earliest: { line: number; uuid: string; timestamp: string | null } | null;
+  latest: { line: number; uuid: string; timestamp: string | null } | null;
+  isSorted: boolean | null;
+  firstUnsortedLine: number | null;
+}
+
 interface ParsedUuid {
   isValid: boolean;
   standardFormat: string;
@@ -9,11 +110,25 @@ interface ParsedUuid {
   version: string;
   variant: string;
   errorMessage?: string;
+  timestamp?: string | null;
+  unixMillis?: number | null;
+  clockSequence?: number | null;
+  nodeId?: string | null;
+  randomBits?: string | null;
+  fields?: UuidField[];
+  customFields?: CustomField[];
+  components?: { name: string; value: string }[];
 }
 
 interface GenerateOptions {
   format: 'standard' | 'no-hyphens' | 'uppercase' | 'uppercase-no-hyphens';
   count: number;
+  namespace?: string;
+  names?: string[];
+  nodeId?: string;
+  clockSequence?: number;
+  template?: V8Field[];
+  seed?: number;
 }
 
 const UuidGenerator: React.FC = () => {
@@ -24,6 +139,27 @@ const UuidGenerator: React.FC = () => {
   const [uuidVersion, setUuidVersion] = useState('v4');
   const [generateCount, setGenerateCount] = useState(1);
   const [isLowercase, setIsLowercase] = useState(true);
+  const [namespace, setNamespace] = useState('dns');
+  const [customNamespace, setCustomNamespace] = useState('');
+  const [names, setNames] = useState('example.com');
+  const isNameBased = uuidVersion === 'v3' || uuidVersion === 'v5';
+  const [nodeId, setNodeId] = useState('');
+  const [clockSequence, setClockSequence] = useState('');
+  const isTimeBased = uuidVersion === 'v1' || uuidVersion === 'v6';
+  const [v8Template, setV8Template] = useState(JSON.stringify(defaultV8Template, null, 2));
+  const [idPrefix, setIdPrefix] = useState('');
+  const [idLength, setIdLength] = useState('');
+  const [idAlphabet, setIdAlphabet] = useState('');
+  const [snowflakeEpoch, setSnowflakeEpoch] = useState('1288834974657');
+  const [workerId, setWorkerId] = useState('0');
+  const isIdFormat = uuidVersion in idFormats;
+  const [seed, setSeed] = useState('');
+  const seedValue = /^\d+$/.test(seed.trim()) ? Number(seed.trim()) : undefined;
+  const [conversions, setConversions] = useState<UuidConversions | null>(null);
+  const [conversionInput, setConversionInput] = useState('auto');
+  const [bulkInput, setBulkInput] = useState('');
+  const [bulkAnalysis, setBulkAnalysis] = useState<UuidListAnalysis | null>(null);
+  const [bulkError, setBulkError] = useState<string | null>(null);
   const [copied, setCopied] = useState<string | null>(null);
   
   // Resizable panels state
@@ -36,13 +172,55 @@ const UuidGenerator: React.FC = () => {
   const handleParse = useCallback(async () => {
     if (!inputUuid.trim()) {
       setParsedUuid(null);
+      setConversions(null);
       return;
     }
 
+    let converted: UuidConversions | null = null;
     try {
-      const result = await invoke<ParsedUuid>('parse_uuid', { uuid: inputUuid.trim() });
+      converted = await invoke<UuidConversions>('convert_uuid', { uuid: inputUuid.trim(), inputFormat: conversionInput });
+    } catch {
+      converted = null;
+    }
+    setConversions(converted);
+
+    let template: V8Field[] | undefined;
+    try {
+      template = JSON.parse(v8Template);
+    } catch {
+      template = undefined;
+    }
+
+    try {
+      // Other representations (base64, decimal, bytes...) are parsed via their hyphenated form
+      const uuid = converted && conversionInput !== 'auto' ? converted.hyphenated : inputUuid.trim();
+      const result = await invoke<ParsedUuid>('parse_uuid', { uuid, template });
       setParsedUuid(result);
     } catch (error) {
+      const format = detectIdFormat(inputUuid.trim());
+      if (format) {
+        try {
+          const options = format === 'snowflake' ? { epoch: parseInt(snowflakeEpoch, 10) || undefined } : undefined;
+          const parsed = await invoke<ParsedId>('parse_id', { format, id: inputUuid.trim(), options });
+          setParsedUuid({
+            isValid: true,
+            standardFormat: inputUuid.trim(),
+            rawContents: '',
+            version: idFormats[parsed.format],
+            variant: '',
+            timestamp: parsed.timestamp,
+            unixMillis: parsed.unixMillis,
+            components: parsed.components,
+          });
+          return;
+        } catch {
+          // Fall through to the UUID error
+        }
+      }
+      if (converted) {
+        setParsedUuid(await invoke<ParsedUuid>('parse_uuid', { uuid: converted.hyphenated, template }));
+        return;
+      }
       setParsedUuid({
         isValid: false,
         standardFormat: '',
@@ -52,14 +230,57 @@ const UuidGenerator: React.FC = () => {
         errorMessage: error as string
       });
     }
-  }, [inputUuid]);
+  }, [inputUuid, v8Template, snowflakeEpoch, conversionInput]);
+
+  const handleAnalyze = useCallback(async () => {
+    setBulkError(null);
+    try {
+      setBulkAnalysis(await invoke<UuidListAnalysis>('analyze_uuids', { text: bulkInput }));
+    } catch (error) {
+      setBulkAnalysis(null);
+      setBulkError(error as string);
+    }
+  }, [bulkInput]);
 
   const handleGenerate = useCallback(async () => {
+    if (isIdFormat) {
+      try {
+        const result = await invoke<string[]>('generate_ids', {
+          format: uuidVersion,
+          options: {
+            count: generateCount,
+            prefix: idPrefix.trim() || undefined,
+            length: idLength.trim() ? parseInt(idLength, 10) : undefined,
+            alphabet: idAlphabet || undefined,
+            epoch: parseInt(snowflakeEpoch, 10) || undefined,
+            workerId: parseInt(workerId, 10) || undefined,
+            seed: seedValue,
+          },
+        });
+        setGeneratedUuids(result);
+      } catch (error) {
+        console.error('Failed to generate IDs:', error);
+      }
+      return;
+    }
+
     try {
       const options: GenerateOptions = {
         format: isLowercase ? 'standard' : 'uppercase',
-        count: generateCount
+        count: generateCount,
+        seed: seedValue
       };
+      if (isNameBased) {
+        options.namespace = namespace === 'custom' ? customNamespace.trim() : namespace;
+        options.names = names.split('\n').filter((name) => name.length > 0);
+      }
+      if (uuidVersion === 'v8') {
+        options.template = JSON.parse(v8Template);
+      }
+      if (isTimeBased) {
+        options.nodeId = nodeId.trim() || undefined;
+        options.clockSequence = clockSequence.trim() ? parseInt(clockSequence, 10) : undefined;
+      }
       
       const result = await invoke<string[]>('generate_uuids', { 
         version: uuidVersion, 
@@ -70,7 +291,7 @@ const UuidGenerator: React.FC = () => {
     } catch (error) {
       console.error('Failed to generate UUIDs:', error);
     }
-  }, [uuidVersion, generateCount, isLowercase]);
+  }, [uuidVersion, generateCount, isLowercase, isNameBased, namespace, customNamespace, names, isTimeBased, nodeId, clockSequence, v8Template, isIdFormat, idPrefix, idLength, idAlphabet, snowflakeEpoch, workerId, seedValue]);
 
   const copyToClipboard = useCallback((text: string, id?: string) => {
     navigator.clipboard.writeText(text);
@@ -328,6 +549,121 @@ const UuidGenerator: React.FC = () => {
               </div>
             </div>
 
+            {[
+              { label: 'Timestamp', value: parsedUuid.timestamp },
+              { label: 'Unix Time (ms)', value: parsedUuid.unixMillis?.toString() },
+              { label: 'Clock Sequence', value: parsedUuid.clockSequence?.toString() },
+              { label: 'Node ID', value: parsedUuid.nodeId },
+              { label: 'Random Bits', value: parsedUuid.randomBits },
+              ...(parsedUuid.components ?? []).map((c) => ({ label: c.name, value: c.value })),
+            ]
+              .filter((detail) => detail.value != null)
+              .map((detail) => (
+                <div key={detail.label} className="space-y-2">
+                  <label className="text-sm font-medium text-gray-700 dark:text-gray-300">{detail.label}</label>
+                  <div className="flex items-center gap-2">
+                    <input
+                      value={detail.value ?? ''}
+                      readOnly
+                      className="flex-1 p-2 font-mono text-sm border border-gray-200 dark:border-gray-700 rounded-lg bg-gray-50 dark:bg-gray-800"
+                    />
+                    <button
+                      onClick={() => copyToClipboard(detail.value ?? '', detail.label)}
+                      className="p-2 text-gray-600 dark:text-gray-400 hover:text-gray-800 dark:hover:text-gray-200"
+                    >
+                      {copied === detail.label ? (
+                        <span className="text-green-600 dark:text-green-400">✓</span>
+                      ) : (
+                        <Copy className="h-4 w-4" />
+                      )}
+                    </button>
+                  </div>
+                </div>
+              ))}
+
+            {parsedUuid.customFields && parsedUuid.customFields.length > 0 && (
+              <div className="space-y-2">
+                <label className="text-sm font-medium text-gray-700 dark:text-gray-300">Custom Fields (v8 template)</label>
+                <table className="w-full text-xs font-mono border border-gray-200 dark:border-gray-700 rounded-lg">
+                  <tbody>
+                    {parsedUuid.customFields.map((field) => (
+                      <tr key={field.name} className="border-t border-gray-200 dark:border-gray-700">
+                        <td className="px-2 py-1">{field.name}</td>
+                        <td className="px-2 py-1 text-gray-500">{field.source}</td>
+                        <td className="px-2 py-1 break-all">{field.timestamp ?? field.value}</td>
+                      </tr>
+                    ))}
+                  </tbody>
+                </table>
+              </div>
+            )}
+
+            {parsedUuid.fields && parsedUuid.fields.length > 0 && (
+              <div className="space-y-2">
+                <label className="text-sm font-medium text-gray-700 dark:text-gray-300">Field Layout</label>
+                <table className="w-full text-xs font-mono border border-gray-200 dark:border-gray-700 rounded-lg">
+                  <thead>
+                    <tr className="text-left text-gray-600 dark:text-gray-400">
+                      <th className="px-2 py-1">Field</th>
+                      <th className="px-2 py-1">Bits</th>
+                      <th className="px-2 py-1">Hex</th>
+                    </tr>
+                  </thead>
+                  <tbody>
+                    {parsedUuid.fields.map((field) => (
+                      <tr key={field.name} className="border-t border-gray-200 dark:border-gray-700" title={field.bits}>
+                        <td className="px-2 py-1">{field.name}</td>
+                        <td className="px-2 py-1">{field.bitOffset}–{field.bitOffset + field.bitWidth - 1}</td>
+                        <td className="px-2 py-1 break-all">{field.hex}</td>
+                      </tr>
+                    ))}
+                  </tbody>
+                </table>
+              </div>
+            )}
+
+            <div className="space-y-2">
+              <div className="flex items-center justify-between">
+                <label className="text-sm font-medium text-gray-700 dark:text-gray-300">
+                  Representations{conversions && ` (input: ${conversions.inputFormat})`}
+                </label>
+                <select
+                  value={conversionInput}
+                  onChange={(e) => setConversionInput(e.target.value)}
+                  className="px-2 py-1 text-xs border border-gray-200 dark:border-gray-700 rounded-lg bg-white dark:bg-gray-800 focus:outline-none focus:ring-2 focus:ring-blue-500"
+                >
+                  <option value="auto">Auto-detect input</option>
+                  <option value="decimal">Input is decimal</option>
+                  <option value="guid-bytes">Input is .NET GUID bytes</option>
+                  <option value="mysql-swap">Input is MySQL swapped hex</option>
+                </select>
+              </div>
+              {conversions && (
+                <table className="w-full text-xs font-mono border border-gray-200 dark:border-gray-700 rounded-lg">
+                  <tbody>
+                    {conversionLabels.map(([key, label]) => (
+                      <tr key={key} className="border-t border-gray-200 dark:border-gray-700">
+                        <td className="px-2 py-1 whitespace-nowrap text-gray-600 dark:text-gray-400">{label}</td>
+                        <td className="px-2 py-1 break-all">{conversions[key]}</td>
+                        <td className="px-2 py-1">
+                          <button
+                            onClick={() => copyToClipboard(conversions[key], key)}
+                            className="text-gray-600 dark:text-gray-400 hover:text-gray-800 dark:hover:text-gray-200"
+                          >
+                            {copied === key ? (
+                              <span className="text-green-600 dark:text-green-400">✓</span>
+                            ) : (
+                              <Copy className="h-3 w-3" />
+                            )}
+                          </button>
+                        </td>
+                      </tr>
+                    ))}
+                  </tbody>
+                </table>
+              )}
+            </div>
+
             {!parsedUuid.isValid && parsedUuid.errorMessage && (
               <div className="p-3 bg-red-50 dark:bg-red-900/20 text-red-600 dark:text-red-400 rounded-md text-sm">
                 {parsedUuid.errorMessage}
@@ -335,6 +671,67 @@ const UuidGenerator: React.FC = () => {
             )}
           </div>
         )}
+
+        <div className="space-y-2 pt-4 border-t border-gray-200 dark:border-gray-700">
+          <div className="flex items-center justify-between">
+            <label className="text-sm font-medium text-gray-700 dark:text-gray-300">Bulk validation (one UUID per line)</label>
+            <button
+              onClick={handleAnalyze}
+              disabled={!bulkInput.trim()}
+              className="px-3 py-1 text-sm bg-blue-600 text-white rounded-lg hover:bg-blue-700 disabled:opacity-50"
+            >
+              Analyze
+            </button>
+          </div>
+          <textarea
+            value={bulkInput}
+            onChange={(e) => setBulkInput(e.target.value)}
+            placeholder="Paste a list of UUIDs..."
+            className="w-full h-32 px-3 py-2 text-xs font-mono border border-gray-200 dark:border-gray-700 rounded-lg bg-white dark:bg-gray-800 focus:outline-none focus:ring-2 focus:ring-blue-500 resize-y"
+          />
+          {bulkError && (
+            <div className="p-3 bg-red-50 dark:bg-red-900/20 text-red-600 dark:text-red-400 rounded-md text-sm">{bulkError}</div>
+          )}
+          {bulkAnalysis && (
+            <div className="space-y-2 text-sm text-gray-700 dark:text-gray-300">
+              <div>
+                {bulkAnalysis.validCount} of {bulkAnalysis.totalLines} lines valid, {bulkAnalysis.uniqueCount} unique
+                {bulkAnalysis.versions.length > 0 && (
+                  <> &middot; {bulkAnalysis.versions.map((v) => `${v.version}: ${v.count}`).join(', ')}</>
+                )}
+              </div>
+              {bulkAnalysis.earliest && bulkAnalysis.latest && (
+                <div className="font-mono text-xs">
+                  <div>Earliest: {bulkAnalysis.earliest.timestamp} (line {bulkAnalysis.earliest.line})</div>
+                  <div>Latest: {bulkAnalysis.latest.timestamp} (line {bulkAnalysis.latest.line})</div>
+                  <div>
+                    {bulkAnalysis.isSorted
+                      ? 'Sorted by timestamp'
+                      : `Not sorted: line ${bulkAnalysis.firstUnsortedLine} is earlier than the UUID before it`}
+                  </div>
+                </div>
+              )}
+              {bulkAnalysis.invalidLines.length > 0 && (
+                <div className="p-2 bg-red-50 dark:bg-red-900/20 rounded-md text-xs font-mono max-h-40 overflow-auto">
+                  {bulkAnalysis.invalidLines.map((invalid) => (
+                    <div key={invalid.line} className="text-red-600 dark:text-red-400">
+                      Line {invalid.line}: {invalid.text} &mdash; {invalid.error}
+                    </div>
+                  ))}
+                </div>
+              )}
+              {bulkAnalysis.duplicates.length > 0 && (
+                <div className="p-2 bg-yellow-50 dark:bg-yellow-900/20 rounded-md text-xs font-mono max-h-40 overflow-auto">
+                  {bulkAnalysis.duplicates.map((duplicate) => (
+                    <div key={duplicate.uuid} className="text-yellow-700 dark:text-yellow-400">
+                      {duplicate.uuid} on lines {duplicate.lines.join(', ')}
+                    </div>
+                  ))}
+                </div>
+              )}
+            </div>
+          )}
+        </div>
       </div>
 
       {/* Resize Handle */}
@@ -366,7 +763,14 @@ const UuidGenerator: React.FC = () => {
                 <option value="v3">UUID v3</option>
                 <option value="v4">UUID v4</option>
                 <option value="v5">UUID v5</option>
-                <option value="v7">UUID v7</option>
+                <option value="v6">UUID v6</option>
+                <option value="v7">UUID v7 (time-ordered)</option>
+                <option value="v8">UUID v8 (custom layout)</option>
+                <optgroup label="Other formats">
+                  {Object.entries(idFormats).map(([key, name]) => (
+                    <option key={key} value={key}>{name}</option>
+                  ))}
+                </optgroup>
               </select>
             </div>
             
@@ -375,6 +779,7 @@ const UuidGenerator: React.FC = () => {
               <input
                 type="number"
                 value={generateCount}
+                disabled={isNameBased}
                 onChange={(e: React.ChangeEvent<HTMLInputElement>) => setGenerateCount(Math.max(1, Math.min(1000, parseInt(e.target.value) || 1)))}
                 className="w-full px-3 py-2 text-sm border border-gray-200 dark:border-gray-700 rounded-lg bg-white dark:bg-gray-800 focus:outline-none focus:ring-2 focus:ring-blue-500"
                 min="1"
@@ -395,6 +800,169 @@ const UuidGenerator: React.FC = () => {
             </div>
           </div>
 
+          {(uuidVersion === 'typeid' || uuidVersion === 'nanoid' || uuidVersion === 'cuid2' || uuidVersion === 'snowflake') && (
+            <div className="grid grid-cols-3 gap-3">
+              {uuidVersion === 'typeid' && (
+                <div className="col-span-3">
+                  <label className="block text-xs font-medium text-gray-600 dark:text-gray-400 mb-1">Prefix</label>
+                  <input
+                    type="text"
+                    value={idPrefix}
+                    onChange={(e: React.ChangeEvent<HTMLInputElement>) => setIdPrefix(e.target.value)}
+                    placeholder="user"
+                    className="w-full px-3 py-2 text-sm font-mono border border-gray-200 dark:border-gray-700 rounded-lg bg-white dark:bg-gray-800 focus:outline-none focus:ring-2 focus:ring-blue-500"
+                  />
+                </div>
+              )}
+              {(uuidVersion === 'nanoid' || uuidVersion === 'cuid2') && (
+                <div>
+                  <label className="block text-xs font-medium text-gray-600 dark:text-gray-400 mb-1">Length</label>
+                  <input
+                    type="number"
+                    value={idLength}
+                    onChange={(e: React.ChangeEvent<HTMLInputElement>) => setIdLength(e.target.value)}
+                    placeholder={uuidVersion === 'nanoid' ? '21' : '24'}
+                    className="w-full px-3 py-2 text-sm border border-gray-200 dark:border-gray-700 rounded-lg bg-white dark:bg-gray-800 focus:outline-none focus:ring-2 focus:ring-blue-500"
+                  />
+                </div>
+              )}
+              {uuidVersion === 'nanoid' && (
+                <div className="col-span-2">
+                  <label className="block text-xs font-medium text-gray-600 dark:text-gray-400 mb-1">Alphabet</label>
+                  <input
+                    type="text"
+                    value={idAlphabet}
+                    onChange={(e: React.ChangeEvent<HTMLInputElement>) => setIdAlphabet(e.target.value)}
+                    placeholder="A-Za-z0-9_-"
+                    className="w-full px-3 py-2 text-sm font-mono border border-gray-200 dark:border-gray-700 rounded-lg bg-white dark:bg-gray-800 focus:outline-none focus:ring-2 focus:ring-blue-500"
+                  />
+                </div>
+              )}
+              {uuidVersion === 'snowflake' && (
+                <>
+                  <div className="col-span-2">
+                    <label className="block text-xs font-medium text-gray-600 dark:text-gray-400 mb-1">Epoch (Unix ms)</label>
+                    <select
+                      value={snowflakeEpoch}
+                      onChange={(e) => setSnowflakeEpoch(e.target.value)}
+                      className="w-full px-3 py-2 text-sm border border-gray-200 dark:border-gray-700 rounded-lg bg-white dark:bg-gray-800 focus:outline-none focus:ring-2 focus:ring-blue-500"
+                    >
+                      <option value="1288834974657">Twitter (1288834974657)</option>
+                      <option value="1420070400000">Discord (1420070400000)</option>
+                    </select>
+                  </div>
+                  <div>
+                    <label className="block text-xs font-medium text-gray-600 dark:text-gray-400 mb-1">Worker ID</label>
+                    <input
+                      type="number"
+                      value={workerId}
+                      onChange={(e: React.ChangeEvent<HTMLInputElement>) => setWorkerId(e.target.value)}
+                      min="0"
+                      max="1023"
+                      className="w-full px-3 py-2 text-sm border border-gray-200 dark:border-gray-700 rounded-lg bg-white dark:bg-gray-800 focus:outline-none focus:ring-2 focus:ring-blue-500"
+                    />
+                  </div>
+                </>
+              )}
+            </div>
+          )}
+
+          {uuidVersion === 'v8' && (
+            <div>
+              <label className="block text-xs font-medium text-gray-600 dark:text-gray-400 mb-1">
+                Layout template (122 bits: timestamp, counter, constant or random fields)
+              </label>
+              <textarea
+                value={v8Template}
+                onChange={(e) => setV8Template(e.target.value)}
+                className="w-full h-40 px-3 py-2 text-xs font-mono border border-gray-200 dark:border-gray-700 rounded-lg bg-white dark:bg-gray-800 focus:outline-none focus:ring-2 focus:ring-blue-500 resize-none"
+              />
+            </div>
+          )}
+
+          {isTimeBased && (
+            <div className="grid grid-cols-3 gap-3">
+              <div className="col-span-2">
+                <label className="block text-xs font-medium text-gray-600 dark:text-gray-400 mb-1">Node ID (MAC)</label>
+                <input
+                  type="text"
+                  value={nodeId}
+                  onChange={(e: React.ChangeEvent<HTMLInputElement>) => setNodeId(e.target.value)}
+                  placeholder="Random multicast node"
+                  className="w-full px-3 py-2 text-sm font-mono border border-gray-200 dark:border-gray-700 rounded-lg bg-white dark:bg-gray-800 focus:outline-none focus:ring-2 focus:ring-blue-500"
+                />
+              </div>
+              <div>
+                <label className="block text-xs font-medium text-gray-600 dark:text-gray-400 mb-1">Clock sequence</label>
+                <input
+                  type="number"
+                  value={clockSequence}
+                  onChange={(e: React.ChangeEvent<HTMLInputElement>) => setClockSequence(e.target.value)}
+                  placeholder="Random"
+                  min="0"
+                  max="16383"
+                  className="w-full px-3 py-2 text-sm border border-gray-200 dark:border-gray-700 rounded-lg bg-white dark:bg-gray-800 focus:outline-none focus:ring-2 focus:ring-blue-500"
+                />
+              </div>
+            </div>
+          )}
+
+          {isNameBased && (
+            <div className="space-y-2">
+              <div className="grid grid-cols-3 gap-3">
+                <div>
+                  <label className="block text-xs font-medium text-gray-600 dark:text-gray-400 mb-1">Namespace</label>
+                  <select
+                    value={namespace}
+                    onChange={(e) => setNamespace(e.target.value)}
+                    className="w-full px-3 py-2 text-sm border border-gray-200 dark:border-gray-700 rounded-lg bg-white dark:bg-gray-800 focus:outline-none focus:ring-2 focus:ring-blue-500"
+                  >
+                    <option value="dns">DNS</option>
+                    <option value="url">URL</option>
+                    <option value="oid">OID</option>
+                    <option value="x500">X.500</option>
+                    <option value="custom">Custom UUID</option>
+                  </select>
+                </div>
+                {namespace === 'custom' && (
+                  <div className="col-span-2">
+                    <label className="block text-xs font-medium text-gray-600 dark:text-gray-400 mb-1">Namespace UUID</label>
+                    <input
+                      type="text"
+                      value={customNamespace}
+                      onChange={(e: React.ChangeEvent<HTMLInputElement>) => setCustomNamespace(e.target.value)}
+                      placeholder="6ba7b810-9dad-11d1-80b4-00c04fd430c8"
+                      className="w-full px-3 py-2 text-sm font-mono border border-gray-200 dark:border-gray-700 rounded-lg bg-white dark:bg-gray-800 focus:outline-none focus:ring-2 focus:ring-blue-500"
+                    />
+                  </div>
+                )}
+              </div>
+              <div>
+                <label className="block text-xs font-medium text-gray-600 dark:text-gray-400 mb-1">Names (one per line)</label>
+                <textarea
+                  value={names}
+                  onChange={(e) => setNames(e.target.value)}
+                  className="w-full h-20 px-3 py-2 text-sm font-mono border border-gray-200 dark:border-gray-700 rounded-lg bg-white dark:bg-gray-800 focus:outline-none focus:ring-2 focus:ring-blue-500 resize-none"
+                />
+              </div>
+            </div>
+          )}
+
+          {!isNameBased && (
+            <div>
+              <label className="block text-xs font-medium text-gray-600 dark:text-gray-400 mb-1">
+                Seed (optional, for reproducible output)
+              </label>
+              <input
+                type="text"
+                value={seed}
+                onChange={(e: React.ChangeEvent<HTMLInputElement>) => setSeed(e.target.value)}
+                placeholder="Random"
+                className="w-full px-3 py-2 text-sm font-mono border border-gray-200 dark:border-gray-700 rounded-lg bg-white dark:bg-gray-800 focus:outline-none focus:ring-2 focus:ring-blue-500"
+              />
+            </div>
+          )}
+
           <div className="grid grid-cols-3 gap-2">
             <button 
               onClick={handleGenerate} 